@@ -0,0 +1,37 @@
+//! Compares the table-based `Unknown::and`/`or` against their mask-based
+//! counterparts. Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use belnapian::{EBelnapian, Unknown};
+
+const ROUNDS: u32 = 10_000;
+
+fn measure(op: fn(Unknown, Unknown) -> EBelnapian) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for a in Unknown::ALL {
+            for b in Unknown::ALL {
+                black_box(op(black_box(a), black_box(b)));
+            }
+        }
+    }
+    start.elapsed()
+}
+
+fn report(name: &str, op: fn(Unknown, Unknown) -> EBelnapian) {
+    let elapsed = measure(op);
+    let calls = ROUNDS * (Unknown::ALL.len() * Unknown::ALL.len()) as u32;
+    println!(
+        "{name:<20} {:>8.2} ns/op",
+        elapsed.as_nanos() as f64 / calls as f64
+    );
+}
+
+fn main() {
+    report("Unknown::and", Unknown::and);
+    report("Unknown::and_masked", Unknown::and_masked);
+    report("Unknown::or", Unknown::or);
+    report("Unknown::or_masked", Unknown::or_masked);
+}
@@ -0,0 +1,29 @@
+//! # no_std smoke test
+//! This crate depends on `belnapian` with `default-features = false`, so
+//! building it on its own (`cargo build -p belnapian-no-std-smoke`) checks that
+//! the core of the library does not need `std` nor `alloc`.
+
+#![no_std]
+
+use belnapian::{Belnapian, TernaryTruth, TruthValuesPowerSet, Unknown};
+
+// The const operations are evaluated at compile time, so a broken table fails
+// the build.
+const _: () = assert!(Belnapian::Both
+    .and(Belnapian::Neither)
+    .eq_const(Belnapian::False));
+const _: () = assert!(TernaryTruth::Unknown
+    .or(TernaryTruth::True)
+    .eq_const(TernaryTruth::True));
+
+/// Computes the Belnapian conjunction of the values pointwise into `out`.
+pub fn and_all(a: &[Belnapian], b: &[Belnapian], out: &mut [Belnapian]) {
+    for ((x, y), o) in a.iter().zip(b).zip(out.iter_mut()) {
+        *o = x.and(*y);
+    }
+}
+
+/// Negates every member of an unknown value, without allocating.
+pub fn not_unknown(value: Unknown) -> TruthValuesPowerSet {
+    TruthValuesPowerSet::from(value).not()
+}
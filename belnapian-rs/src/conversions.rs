@@ -0,0 +1,594 @@
+//! # Conversions
+//! This module centralizes every conversion between the truth value types of
+//! this crate (and `bool`). All of them are implemented through the standard
+//! [`From`] and [`TryFrom`] traits, so this module only exports the error type
+//! used by the fallible ones; the table below documents their semantics.
+//!
+//! | From                    | Into                    | Trait     | Notes                                                 |
+//! |-------------------------|-------------------------|-----------|-------------------------------------------------------|
+//! | `bool`                  | `Belnapian`             | `From`    | `false → False`, `true → True`                        |
+//! | `Belnapian`             | `bool`                  | `TryFrom` | Fails for `Neither` and `Both`                        |
+//! | `bool`                  | `TernaryTruth`          | `From`    | `false → False`, `true → True`                        |
+//! | `TernaryTruth`          | `bool`                  | `TryFrom` | Fails for `Unknown`                                   |
+//! | `Option<bool>`          | `TernaryTruth`          | `From`    | `None → Unknown`                                      |
+//! | `TernaryTruth`          | `Option<bool>`          | `From`    | `Unknown → None`                                      |
+//! | `bool`                  | `EBelnapian`            | `From`    | `false → Known(False)`, `true → Known(True)`          |
+//! | `EBelnapian`            | `bool`                  | `TryFrom` | Only `Known(False)` and `Known(True)` succeed         |
+//! | `Belnapian`             | `TernaryTruth`          | `TryFrom` | Fails for `Neither` and `Both`                        |
+//! | `TernaryTruth`          | `Belnapian`             | `TryFrom` | Fails for `Unknown`                                   |
+//! | `TernaryTruth`          | `EBelnapian`            | `From`    | `Unknown → Unknown(_FT_)`                             |
+//! | `EBelnapian`            | `TernaryTruth`          | `TryFrom` | Only `Known(False)`, `Known(True)`, `Unknown(_FT_)`   |
+//! | `Unknown`               | `TernaryTruth`          | `TryFrom` | Only `_FT_` succeeds                                  |
+//! | `TernaryTruth`          | `Unknown`               | `TryFrom` | Only `Unknown` succeeds, mapping to `_FT_`            |
+//! | `Belnapian`             | `EBelnapian`            | `From`    | Wraps it in `Known`                                   |
+//! | `EBelnapian`            | `Belnapian`             | `TryFrom` | Only `Known(_)` succeeds                              |
+//! | `Unknown`               | `EBelnapian`            | `From`    | Wraps it in `Unknown`                                 |
+//! | `EBelnapian`            | `Unknown`               | `TryFrom` | Only `Unknown(_)` succeeds                            |
+//! | `Unknown`               | `TruthValuesPowerSet`   | `From`    | Maps to the set with the same members                 |
+//! | `TruthValuesPowerSet`   | `Unknown`               | `TryFrom` | Fails for the empty set and the singletons            |
+//! | `Belnapian`             | `TruthValuesPowerSet`   | `From`    | Maps to the singleton with the same member            |
+//! | `EBelnapian`            | `TruthValuesPowerSet`   | `From`    | `Known(x)` → singleton, `Unknown(u)` → same members   |
+//! | `TruthValuesPowerSet`   | `EBelnapian`            | `TryFrom` | Only fails for the empty set                          |
+//! | `TruthValuesPowerSet`   | `Belnapian`             | `TryFrom` | Only the singletons succeed                           |
+//! | `TruthValuesPowerSet`   | `TernaryTruth`          | `TryFrom` | Only `_F__`, `__T_`, and `_FT_` succeed               |
+//! | `TruthValuesPowerSet`   | `u8`                    | `From`    | The 4-bit mask, see `TruthValuesPowerSet::to_bits`    |
+//! | `u8`                    | `TruthValuesPowerSet`   | `TryFrom` | Fails for values above `15`                           |
+//!
+//! Additionally, [`Belnapian`] and [`TernaryTruth`] implement [`FromStr`],
+//! accepting the same labels produced by their `Display` impls (ignoring ASCII
+//! case), and failing with [`ConversionError::UnknownLabel`] otherwise.
+//!
+//! Finally, every truth value type has a stable string tag, meant for
+//! persisting values: `to_tag` produces it and `from_tag` parses it back
+//! (case-sensitively), failing with [`ConversionError::UnknownLabel`] for any
+//! other string. The tags of [`Belnapian`] and [`TernaryTruth`] are their
+//! variant names (`"False"`, `"Unknown"`, ...), the tags of [`Unknown`] and
+//! [`TruthValuesPowerSet`] are their variant names too (`"_FT_"`, `"____"`,
+//! `"N___"`, ...), and [`EBelnapian`] uses `"False"` for `Known(False)` and
+//! `"Unknown(_FT_)"` for `Unknown(_FT_)`.
+
+use core::{fmt, str::FromStr};
+
+use crate::{Belnapian, EBelnapian, TernaryTruth, TruthValuesPowerSet, Unknown};
+
+// Errors
+// -----------------------------------------------------------------------------
+
+/// The error returned by the fallible conversions of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConversionError {
+    /// The source value is the empty set, which has no counterpart in the
+    /// target type.
+    EmptySet,
+
+    /// The source value is a set with more than one member, but the target type
+    /// can only represent a single truth value.
+    NotSingleton,
+
+    /// The source value has no counterpart among the classical (and ternary
+    /// unknown) values of the target type.
+    NotClassical,
+
+    /// The source value is a raw bitset with bits set outside of the range
+    /// used by the target type.
+    OutOfRange,
+
+    /// The source value is known (a single truth value), but the target type
+    /// can only represent unknown values.
+    NotUnknown,
+
+    /// The source string is not the label of any value of the target type.
+    UnknownLabel,
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::EmptySet => write!(f, "the empty set has no counterpart"),
+            ConversionError::NotSingleton => write!(f, "the set has more than one member"),
+            ConversionError::NotClassical => write!(f, "the value has no classical counterpart"),
+            ConversionError::NotUnknown => write!(f, "the value is not unknown"),
+            ConversionError::OutOfRange => write!(f, "the bitset has bits out of range"),
+            ConversionError::UnknownLabel => write!(f, "the string is not a known label"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+// -----------------------------------------------------------------------------
+// Bool:
+
+impl From<bool> for Belnapian {
+    fn from(value: bool) -> Self {
+        match value {
+            false => Belnapian::False,
+            true => Belnapian::True,
+        }
+    }
+}
+
+impl TryFrom<Belnapian> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: Belnapian) -> Result<bool, Self::Error> {
+        match value {
+            Belnapian::False => Ok(false),
+            Belnapian::True => Ok(true),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+impl From<bool> for TernaryTruth {
+    fn from(value: bool) -> Self {
+        match value {
+            false => TernaryTruth::False,
+            true => TernaryTruth::True,
+        }
+    }
+}
+
+impl TryFrom<TernaryTruth> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: TernaryTruth) -> Result<bool, Self::Error> {
+        match value {
+            TernaryTruth::False => Ok(false),
+            TernaryTruth::True => Ok(true),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+impl From<Option<bool>> for TernaryTruth {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(false) => TernaryTruth::False,
+            Some(true) => TernaryTruth::True,
+            None => TernaryTruth::Unknown,
+        }
+    }
+}
+
+impl From<TernaryTruth> for Option<bool> {
+    fn from(value: TernaryTruth) -> Self {
+        match value {
+            TernaryTruth::False => Some(false),
+            TernaryTruth::True => Some(true),
+            TernaryTruth::Unknown => None,
+        }
+    }
+}
+
+impl From<bool> for EBelnapian {
+    fn from(value: bool) -> Self {
+        match value {
+            false => EBelnapian::Known(Belnapian::False),
+            true => EBelnapian::Known(Belnapian::True),
+        }
+    }
+}
+
+impl TryFrom<EBelnapian> for bool {
+    type Error = ConversionError;
+
+    fn try_from(value: EBelnapian) -> Result<bool, Self::Error> {
+        match value {
+            EBelnapian::Known(Belnapian::False) => Ok(false),
+            EBelnapian::Known(Belnapian::True) => Ok(true),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Strings:
+
+impl FromStr for Belnapian {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<Belnapian, Self::Err> {
+        if s.eq_ignore_ascii_case("neither") {
+            Ok(Belnapian::Neither)
+        } else if s.eq_ignore_ascii_case("false") {
+            Ok(Belnapian::False)
+        } else if s.eq_ignore_ascii_case("true") {
+            Ok(Belnapian::True)
+        } else if s.eq_ignore_ascii_case("both") {
+            Ok(Belnapian::Both)
+        } else {
+            Err(ConversionError::UnknownLabel)
+        }
+    }
+}
+
+impl FromStr for TernaryTruth {
+    type Err = ConversionError;
+
+    fn from_str(s: &str) -> Result<TernaryTruth, Self::Err> {
+        if s.eq_ignore_ascii_case("false") {
+            Ok(TernaryTruth::False)
+        } else if s.eq_ignore_ascii_case("true") {
+            Ok(TernaryTruth::True)
+        } else if s.eq_ignore_ascii_case("unknown") {
+            Ok(TernaryTruth::Unknown)
+        } else {
+            Err(ConversionError::UnknownLabel)
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Tags:
+
+impl Belnapian {
+    /// Returns the stable string tag of the value.
+    pub fn to_tag(self) -> &'static str {
+        match self {
+            Belnapian::Neither => "Neither",
+            Belnapian::False => "False",
+            Belnapian::True => "True",
+            Belnapian::Both => "Both",
+        }
+    }
+
+    /// Parses a tag produced by [`Belnapian::to_tag`].
+    pub fn from_tag(tag: &str) -> Result<Belnapian, ConversionError> {
+        Belnapian::ALL
+            .into_iter()
+            .find(|value| value.to_tag() == tag)
+            .ok_or(ConversionError::UnknownLabel)
+    }
+}
+
+impl TernaryTruth {
+    /// Returns the stable string tag of the value.
+    pub fn to_tag(self) -> &'static str {
+        match self {
+            TernaryTruth::False => "False",
+            TernaryTruth::True => "True",
+            TernaryTruth::Unknown => "Unknown",
+        }
+    }
+
+    /// Parses a tag produced by [`TernaryTruth::to_tag`].
+    pub fn from_tag(tag: &str) -> Result<TernaryTruth, ConversionError> {
+        TernaryTruth::ALL
+            .into_iter()
+            .find(|value| value.to_tag() == tag)
+            .ok_or(ConversionError::UnknownLabel)
+    }
+}
+
+impl Unknown {
+    /// Returns the stable string tag of the value.
+    pub fn to_tag(self) -> &'static str {
+        match self {
+            Unknown::NF__ => "NF__",
+            Unknown::N_T_ => "N_T_",
+            Unknown::_FT_ => "_FT_",
+            Unknown::NFT_ => "NFT_",
+            Unknown::N__B => "N__B",
+            Unknown::_F_B => "_F_B",
+            Unknown::NF_B => "NF_B",
+            Unknown::__TB => "__TB",
+            Unknown::N_TB => "N_TB",
+            Unknown::_FTB => "_FTB",
+            Unknown::NFTB => "NFTB",
+        }
+    }
+
+    /// Parses a tag produced by [`Unknown::to_tag`].
+    pub fn from_tag(tag: &str) -> Result<Unknown, ConversionError> {
+        Unknown::ALL
+            .into_iter()
+            .find(|value| value.to_tag() == tag)
+            .ok_or(ConversionError::UnknownLabel)
+    }
+}
+
+impl TruthValuesPowerSet {
+    /// Returns the stable string tag of the value.
+    pub fn to_tag(self) -> &'static str {
+        match self {
+            TruthValuesPowerSet::____ => "____",
+            TruthValuesPowerSet::N___ => "N___",
+            TruthValuesPowerSet::_F__ => "_F__",
+            TruthValuesPowerSet::NF__ => "NF__",
+            TruthValuesPowerSet::__T_ => "__T_",
+            TruthValuesPowerSet::N_T_ => "N_T_",
+            TruthValuesPowerSet::_FT_ => "_FT_",
+            TruthValuesPowerSet::NFT_ => "NFT_",
+            TruthValuesPowerSet::___B => "___B",
+            TruthValuesPowerSet::N__B => "N__B",
+            TruthValuesPowerSet::_F_B => "_F_B",
+            TruthValuesPowerSet::NF_B => "NF_B",
+            TruthValuesPowerSet::__TB => "__TB",
+            TruthValuesPowerSet::N_TB => "N_TB",
+            TruthValuesPowerSet::_FTB => "_FTB",
+            TruthValuesPowerSet::NFTB => "NFTB",
+        }
+    }
+
+    /// Parses a tag produced by [`TruthValuesPowerSet::to_tag`].
+    pub fn from_tag(tag: &str) -> Result<TruthValuesPowerSet, ConversionError> {
+        TruthValuesPowerSet::ALL
+            .into_iter()
+            .find(|value| value.to_tag() == tag)
+            .ok_or(ConversionError::UnknownLabel)
+    }
+}
+
+impl EBelnapian {
+    /// Returns the stable string tag of the value.
+    pub fn to_tag(self) -> &'static str {
+        match self {
+            EBelnapian::Known(value) => value.to_tag(),
+            EBelnapian::Unknown(Unknown::NF__) => "Unknown(NF__)",
+            EBelnapian::Unknown(Unknown::N_T_) => "Unknown(N_T_)",
+            EBelnapian::Unknown(Unknown::_FT_) => "Unknown(_FT_)",
+            EBelnapian::Unknown(Unknown::NFT_) => "Unknown(NFT_)",
+            EBelnapian::Unknown(Unknown::N__B) => "Unknown(N__B)",
+            EBelnapian::Unknown(Unknown::_F_B) => "Unknown(_F_B)",
+            EBelnapian::Unknown(Unknown::NF_B) => "Unknown(NF_B)",
+            EBelnapian::Unknown(Unknown::__TB) => "Unknown(__TB)",
+            EBelnapian::Unknown(Unknown::N_TB) => "Unknown(N_TB)",
+            EBelnapian::Unknown(Unknown::_FTB) => "Unknown(_FTB)",
+            EBelnapian::Unknown(Unknown::NFTB) => "Unknown(NFTB)",
+        }
+    }
+
+    /// Parses a tag produced by [`EBelnapian::to_tag`].
+    pub fn from_tag(tag: &str) -> Result<EBelnapian, ConversionError> {
+        EBelnapian::ALL
+            .into_iter()
+            .find(|value| value.to_tag() == tag)
+            .ok_or(ConversionError::UnknownLabel)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TernaryTruth:
+
+impl TryFrom<Belnapian> for TernaryTruth {
+    type Error = ConversionError;
+
+    fn try_from(value: Belnapian) -> Result<TernaryTruth, Self::Error> {
+        match value {
+            Belnapian::False => Ok(TernaryTruth::False),
+            Belnapian::True => Ok(TernaryTruth::True),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+impl TryFrom<TernaryTruth> for Belnapian {
+    type Error = ConversionError;
+
+    fn try_from(value: TernaryTruth) -> Result<Belnapian, Self::Error> {
+        match value {
+            TernaryTruth::False => Ok(Belnapian::False),
+            TernaryTruth::True => Ok(Belnapian::True),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+/// The "narrow" interpretation: `TernaryTruth::Unknown` means that we know the
+/// proposition is classical, but not whether it's `False` or `True`, so it maps
+/// to `Unknown(_FT_)`. See [`EBelnapian::from_ternary_wide`] for the "wide"
+/// interpretation.
+impl From<TernaryTruth> for EBelnapian {
+    fn from(value: TernaryTruth) -> Self {
+        match value {
+            TernaryTruth::False => EBelnapian::Known(Belnapian::False),
+            TernaryTruth::True => EBelnapian::Known(Belnapian::True),
+            TernaryTruth::Unknown => EBelnapian::Unknown(Unknown::_FT_),
+        }
+    }
+}
+
+impl TryFrom<EBelnapian> for TernaryTruth {
+    type Error = ConversionError;
+
+    fn try_from(value: EBelnapian) -> Result<TernaryTruth, Self::Error> {
+        match value {
+            EBelnapian::Known(Belnapian::False) => Ok(TernaryTruth::False),
+            EBelnapian::Known(Belnapian::True) => Ok(TernaryTruth::True),
+            EBelnapian::Unknown(Unknown::_FT_) => Ok(TernaryTruth::Unknown),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+impl TryFrom<Unknown> for TernaryTruth {
+    type Error = ConversionError;
+
+    fn try_from(value: Unknown) -> Result<TernaryTruth, Self::Error> {
+        match value {
+            Unknown::_FT_ => Ok(TernaryTruth::Unknown),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
+
+impl TryFrom<TernaryTruth> for Unknown {
+    type Error = ConversionError;
+
+    fn try_from(value: TernaryTruth) -> Result<Unknown, Self::Error> {
+        match value {
+            TernaryTruth::Unknown => Ok(Unknown::_FT_),
+            _ => Err(ConversionError::NotUnknown),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Belnapian:
+
+impl From<Belnapian> for EBelnapian {
+    fn from(value: Belnapian) -> Self {
+        EBelnapian::Known(value)
+    }
+}
+
+impl TryFrom<EBelnapian> for Belnapian {
+    type Error = ConversionError;
+
+    fn try_from(value: EBelnapian) -> Result<Belnapian, Self::Error> {
+        match value {
+            EBelnapian::Known(value) => Ok(value),
+            _ => Err(ConversionError::NotSingleton),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Unknown:
+
+impl From<Unknown> for EBelnapian {
+    fn from(value: Unknown) -> Self {
+        EBelnapian::Unknown(value)
+    }
+}
+
+impl TryFrom<EBelnapian> for Unknown {
+    type Error = ConversionError;
+
+    fn try_from(value: EBelnapian) -> Result<Unknown, Self::Error> {
+        match value {
+            EBelnapian::Unknown(value) => Ok(value),
+            _ => Err(ConversionError::NotUnknown),
+        }
+    }
+}
+
+impl From<Unknown> for TruthValuesPowerSet {
+    fn from(value: Unknown) -> Self {
+        match value {
+            Unknown::NF__ => TruthValuesPowerSet::NF__,
+            Unknown::N_T_ => TruthValuesPowerSet::N_T_,
+            Unknown::_FT_ => TruthValuesPowerSet::_FT_,
+            Unknown::NFT_ => TruthValuesPowerSet::NFT_,
+            Unknown::N__B => TruthValuesPowerSet::N__B,
+            Unknown::_F_B => TruthValuesPowerSet::_F_B,
+            Unknown::NF_B => TruthValuesPowerSet::NF_B,
+            Unknown::__TB => TruthValuesPowerSet::__TB,
+            Unknown::N_TB => TruthValuesPowerSet::N_TB,
+            Unknown::_FTB => TruthValuesPowerSet::_FTB,
+            Unknown::NFTB => TruthValuesPowerSet::NFTB,
+        }
+    }
+}
+
+impl TryFrom<TruthValuesPowerSet> for Unknown {
+    type Error = ConversionError;
+
+    fn try_from(value: TruthValuesPowerSet) -> Result<Unknown, Self::Error> {
+        match value {
+            TruthValuesPowerSet::NF__ => Ok(Unknown::NF__),
+            TruthValuesPowerSet::N_T_ => Ok(Unknown::N_T_),
+            TruthValuesPowerSet::_FT_ => Ok(Unknown::_FT_),
+            TruthValuesPowerSet::NFT_ => Ok(Unknown::NFT_),
+            TruthValuesPowerSet::N__B => Ok(Unknown::N__B),
+            TruthValuesPowerSet::_F_B => Ok(Unknown::_F_B),
+            TruthValuesPowerSet::NF_B => Ok(Unknown::NF_B),
+            TruthValuesPowerSet::__TB => Ok(Unknown::__TB),
+            TruthValuesPowerSet::N_TB => Ok(Unknown::N_TB),
+            TruthValuesPowerSet::_FTB => Ok(Unknown::_FTB),
+            TruthValuesPowerSet::NFTB => Ok(Unknown::NFTB),
+            TruthValuesPowerSet::____ => Err(ConversionError::EmptySet),
+            _ => Err(ConversionError::NotUnknown),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TruthValuesPowerSet:
+
+/// See [`TruthValuesPowerSet::to_bits`].
+impl From<TruthValuesPowerSet> for u8 {
+    fn from(value: TruthValuesPowerSet) -> Self {
+        value.to_bits()
+    }
+}
+
+/// See [`TruthValuesPowerSet::from_bitset_checked`].
+impl TryFrom<u8> for TruthValuesPowerSet {
+    type Error = ConversionError;
+
+    fn try_from(value: u8) -> Result<TruthValuesPowerSet, Self::Error> {
+        TruthValuesPowerSet::from_bitset_checked(value)
+    }
+}
+
+impl From<Belnapian> for TruthValuesPowerSet {
+    fn from(value: Belnapian) -> Self {
+        match value {
+            Belnapian::Neither => TruthValuesPowerSet::N___,
+            Belnapian::False => TruthValuesPowerSet::_F__,
+            Belnapian::True => TruthValuesPowerSet::__T_,
+            Belnapian::Both => TruthValuesPowerSet::___B,
+        }
+    }
+}
+
+/// Every `EBelnapian` value maps to a non-empty set: `Known(x)` to the
+/// singleton `{x}`, and `Unknown(u)` to the set with the same members as `u`.
+impl From<EBelnapian> for TruthValuesPowerSet {
+    fn from(value: EBelnapian) -> Self {
+        match value {
+            EBelnapian::Known(value) => value.into(),
+            EBelnapian::Unknown(value) => value.into(),
+        }
+    }
+}
+
+impl TryFrom<TruthValuesPowerSet> for EBelnapian {
+    type Error = ConversionError;
+
+    fn try_from(value: TruthValuesPowerSet) -> Result<EBelnapian, Self::Error> {
+        match value {
+            TruthValuesPowerSet::____ => Err(ConversionError::EmptySet),
+            TruthValuesPowerSet::N___ => Ok(EBelnapian::Known(Belnapian::Neither)),
+            TruthValuesPowerSet::_F__ => Ok(EBelnapian::Known(Belnapian::False)),
+            TruthValuesPowerSet::__T_ => Ok(EBelnapian::Known(Belnapian::True)),
+            TruthValuesPowerSet::___B => Ok(EBelnapian::Known(Belnapian::Both)),
+            _ => match Unknown::try_from(value) {
+                Ok(value) => Ok(EBelnapian::Unknown(value)),
+                Err(_) => unreachable!(), // Only the empty set and the singletons fail
+            },
+        }
+    }
+}
+
+impl TryFrom<TruthValuesPowerSet> for Belnapian {
+    type Error = ConversionError;
+
+    fn try_from(value: TruthValuesPowerSet) -> Result<Belnapian, Self::Error> {
+        match value {
+            TruthValuesPowerSet::____ => Err(ConversionError::EmptySet),
+            TruthValuesPowerSet::N___ => Ok(Belnapian::Neither),
+            TruthValuesPowerSet::_F__ => Ok(Belnapian::False),
+            TruthValuesPowerSet::__T_ => Ok(Belnapian::True),
+            TruthValuesPowerSet::___B => Ok(Belnapian::Both),
+            _ => Err(ConversionError::NotSingleton),
+        }
+    }
+}
+
+impl TryFrom<TruthValuesPowerSet> for TernaryTruth {
+    type Error = ConversionError;
+
+    fn try_from(value: TruthValuesPowerSet) -> Result<TernaryTruth, Self::Error> {
+        match value {
+            TruthValuesPowerSet::____ => Err(ConversionError::EmptySet),
+            TruthValuesPowerSet::_F__ => Ok(TernaryTruth::False),
+            TruthValuesPowerSet::__T_ => Ok(TernaryTruth::True),
+            TruthValuesPowerSet::_FT_ => Ok(TernaryTruth::Unknown),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! # FFI
+//! C ABI wrappers over the [`Belnapian`] connectives, enabled by the `ffi`
+//! feature. Values are passed as their stable discriminants (see
+//! [`Belnapian::as_u8`]): `Neither = 0`, `False = 1`, `True = 2`, and
+//! `Both = 3`.
+//!
+//! Every function returns [`BELNAPIAN_INVALID`] if any of its arguments is not
+//! a valid discriminant. To call them from C, build the crate as a `cdylib` or
+//! `staticlib` and declare them as, e.g.,
+//! `uint8_t belnapian_and(uint8_t a, uint8_t b);`.
+
+use crate::Belnapian;
+
+/// Returned by the FFI functions when they get an invalid discriminant.
+pub const BELNAPIAN_INVALID: u8 = u8::MAX;
+
+macro_rules! extern_binary {
+    ($($name:ident => $method:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("C ABI wrapper over [`Belnapian::", stringify!($method), "`].")]
+            #[no_mangle]
+            pub extern "C" fn $name(a: u8, b: u8) -> u8 {
+                match (Belnapian::from_u8(a), Belnapian::from_u8(b)) {
+                    (Some(a), Some(b)) => a.$method(b).as_u8(),
+                    _ => BELNAPIAN_INVALID,
+                }
+            }
+        )*
+    };
+}
+
+extern_binary! {
+    belnapian_and => and,
+    belnapian_or => or,
+    belnapian_xor => xor,
+    belnapian_implies => implies,
+    belnapian_nand => nand,
+    belnapian_nor => nor,
+    belnapian_superposition => superposition,
+    belnapian_annihilation => annihilation,
+}
+
+/// C ABI wrapper over [`Belnapian::not`].
+#[no_mangle]
+pub extern "C" fn belnapian_not(a: u8) -> u8 {
+    match Belnapian::from_u8(a) {
+        Some(a) => a.not().as_u8(),
+        None => BELNAPIAN_INVALID,
+    }
+}
@@ -0,0 +1,73 @@
+//! # Formulas
+//! A minimal formula representation, generic over the truth value type, that can
+//! be evaluated against an assignment of values to its variables.
+
+use alloc::boxed::Box;
+
+use crate::LogicOperand;
+
+/// A propositional formula over truth values of type `V`. Variables are
+/// identified by their index in the assignment passed to [`Formula::eval`].
+///
+/// Formulas can be built fluently:
+/// ```
+/// use belnapian::{Belnapian, Formula};
+///
+/// let formula = Formula::var(0).and(Formula::var(1).not());
+/// assert_eq!(
+///     Belnapian::True,
+///     formula.eval(&[Belnapian::True, Belnapian::False])
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Formula<V> {
+    Var(usize),
+    Const(V),
+    Not(Box<Formula<V>>),
+    And(Box<Formula<V>>, Box<Formula<V>>),
+    Or(Box<Formula<V>>, Box<Formula<V>>),
+    Implies(Box<Formula<V>>, Box<Formula<V>>),
+}
+
+impl<V> Formula<V> {
+    pub fn var(index: usize) -> Self {
+        Formula::Var(index)
+    }
+
+    pub fn constant(value: V) -> Self {
+        Formula::Const(value)
+    }
+
+    pub fn not(self) -> Self {
+        Formula::Not(Box::new(self))
+    }
+
+    pub fn and(self, other: Self) -> Self {
+        Formula::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: Self) -> Self {
+        Formula::Or(Box::new(self), Box::new(other))
+    }
+
+    pub fn implies(self, other: Self) -> Self {
+        Formula::Implies(Box::new(self), Box::new(other))
+    }
+}
+
+impl<V: LogicOperand> Formula<V> {
+    /// Evaluates the formula, taking the value of the variable `i` from
+    /// `assignment[i]`.
+    ///
+    /// Panics if the formula refers to a variable outside of `assignment`.
+    pub fn eval(&self, assignment: &[V]) -> V {
+        match self {
+            Formula::Var(index) => assignment[*index],
+            Formula::Const(value) => *value,
+            Formula::Not(a) => !a.eval(assignment),
+            Formula::And(a, b) => a.eval(assignment).and(b.eval(assignment)),
+            Formula::Or(a, b) => a.eval(assignment).or(b.eval(assignment)),
+            Formula::Implies(a, b) => a.eval(assignment).implies(b.eval(assignment)),
+        }
+    }
+}
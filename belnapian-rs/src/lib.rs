@@ -15,9 +15,14 @@
 //! ```
 //!
 //! Belnapian also provides the basic operations `and`, `or`, `not` and `xor`,
-//! but it does not provide "implication" connectives, as they differ between
-//! different 3-valued logic systems such as Kleene logic, RM3 logic, or
-//! [Łukasiewicz logic](https://en.wikipedia.org/wiki/%C5%81ukasiewicz_logic).
+//! as well as "implication" connectives (through `implies`, the [`ImpliesOp`]
+//! trait, and the `>>` operator). As implication differs between different
+//! 3-valued logic systems such as Kleene logic, RM3 logic, or
+//! [Łukasiewicz logic](https://en.wikipedia.org/wiki/%C5%81ukasiewicz_logic),
+//! each type picks one explicitly: [`TernaryTruth::implies`] is Kleene's
+//! implication (`¬a ∨ b`), with [`TernaryTruth::lukasiewicz_implies`] as the
+//! Łukasiewicz alternative, while [`Belnapian::implies`] and
+//! [`EBelnapian::implies`] are the material implication `¬a ∨ b`.
 //!
 //! My personal recommendation on using these "truth values" is to treat them not as
 //! the actual truth values attributed to a proposition, but to treat them as our
@@ -107,20 +112,52 @@
 //! pre-computed tables to save you a ton of time when dealing with uncertainty in
 //! logic calculations.
 
-use std::ops;
+// We favour explicit `match` tables (they are easier to audit against the
+// truth tables), and inherent `not` methods that mirror `ops::Not`.
+#![allow(clippy::match_like_matches_macro, clippy::should_implement_trait)]
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::BTreeMap, vec, vec::Vec};
+use core::{fmt, ops};
+
+pub mod conversions;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "alloc")]
+pub mod formula;
+
+pub use conversions::ConversionError;
+#[cfg(feature = "alloc")]
+pub use formula::Formula;
 
 // Enums
 // -----------------------------------------------------------------------------
 
 /// See [Wikipedia :: Four-valued Logic :: Belnap](https://en.wikipedia.org/wiki/Four-valued_logic#Belnap)
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// The values are deliberately not ordered with `<`: the declaration order does
+/// not match any of the two orders of Belnap's bilattice. Use [`TruthOrder`] or
+/// [`KnowledgeOrder`] instead.
+///
+/// The discriminants are stable (`Neither = 0`, `False = 1`, `True = 2`,
+/// `Both = 3`), so they can be used for FFI and serialization, see
+/// [`Belnapian::as_u8`] and [`Belnapian::from_u8`]. Bit 0 is the "told false"
+/// bit, and bit 1 is the "told true" bit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
 pub enum Belnapian {
     /// The `Neither` truth value is useful to identify propositions to which we
     /// cannot assign any classical truth value. This often happens when the
     /// proposition is not well-formed or when it is self-contradictory.
-    Neither,
-    False,
-    True,
+    Neither = 0,
+    False = 1,
+    True = 2,
 
     /// We can understand `Both` as a superposition of `True` and `False`. A natural
     /// case where it makes sense to assign this truth value is when we have a
@@ -131,10 +168,16 @@ pub enum Belnapian {
     /// In other words, in case that a proposition (or its negation) is independent
     /// of our axioms and could be added as a new axiom without causing any
     /// inconsistency, then we can assign the `Both` truth value to it.
-    Both,
+    Both = 3,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Kleene's 3-valued logic.
+///
+/// The derived `==` (and `Hash`) is structural: it tells whether two values are
+/// the same variant, and always yields a `bool`. It is different from the
+/// logical [`TernaryTruth::eq`] method, which yields a `TernaryTruth` (e.g.
+/// `Unknown.eq(Unknown)` is `Unknown`, while `Unknown == Unknown` is `true`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TernaryTruth {
     False,
     True,
@@ -144,7 +187,7 @@ pub enum TernaryTruth {
 /// The [`TruthValuesPowerSet`] enum represents power sets of the set of 4 truth
 /// values in Belnap's 4-valued logic. It's a superset of the [`Unknown`] enum.
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TruthValuesPowerSet {
     ____, // Empty set, if we reach it, then there is an inconsistency somewhere
     N___, // Known
@@ -172,7 +215,7 @@ pub enum TruthValuesPowerSet {
 /// elements, and then remove the empty set and every set with only one element,
 /// leaving us with 11.
 #[allow(non_camel_case_types)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Unknown {
     NF__,
     N_T_,
@@ -187,20 +230,163 @@ pub enum Unknown {
     NFTB,
 }
 
+/// The [`CanonicalValue`] enum classifies the [`TruthValuesPowerSet`] variants
+/// into the empty set, the known values (singletons), and the unknown values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CanonicalValue {
+    Empty,
+    Known(Belnapian),
+    Unknown(Unknown),
+}
+
 /// The [`EBelnapian`] enum represents a "union" of the [`Belnapian`] and
 /// [`Unknown`] enums.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum EBelnapian {
     Known(Belnapian),
     Unknown(Unknown),
 }
 
+/// The built-in binary connectives over [`Belnapian`] values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connective {
+    And,
+    Or,
+    Xor,
+    Implies,
+    Iff,
+    Nand,
+    Nor,
+    Superposition,
+    Annihilation,
+}
+
+/// The symmetries of FOUR that preserve its bilattice structure, see
+/// [`Belnapian::apply_symmetry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry {
+    /// Leaves every value unchanged.
+    Identity,
+    /// Swaps `True` and `False`, fixing `Neither` and `Both`.
+    Negation,
+    /// Swaps `Neither` and `Both`, fixing `True` and `False`.
+    Conflation,
+    /// Negation followed by conflation (they commute).
+    NegationConflation,
+}
+
+/// How much two [`EBelnapian`] values agree, see [`EBelnapian::agreement`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Agreement {
+    /// Both values admit exactly the same truth values.
+    Identical,
+    /// The values admit some truth values in common, but not all of them.
+    Overlapping,
+    /// The values have no truth value in common.
+    Disjoint,
+}
+
+// Structs
+// -----------------------------------------------------------------------------
+
+/// The results of applying every binary connective to the same pair of
+/// [`Belnapian`] values, see [`Belnapian::all_ops`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BinaryResults {
+    pub and: Belnapian,
+    pub or: Belnapian,
+    pub xor: Belnapian,
+    pub implies: Belnapian,
+    pub iff: Belnapian,
+    pub superposition: Belnapian,
+    pub annihilation: Belnapian,
+
+    /// See [`Belnapian::eq`].
+    pub eq: Belnapian,
+}
+
+/// Compares the wrapped [`Belnapian`] values in the truth order (see
+/// [`Belnapian::truth_le`]). It's a partial order: `Neither` and `Both` are
+/// incomparable.
+///
+/// ```
+/// use belnapian::{Belnapian, TruthOrder};
+///
+/// assert!(TruthOrder(Belnapian::False) < TruthOrder(Belnapian::Both));
+/// assert!(TruthOrder(Belnapian::Both) < TruthOrder(Belnapian::True));
+/// assert_eq!(
+///     None,
+///     TruthOrder(Belnapian::Neither).partial_cmp(&TruthOrder(Belnapian::Both))
+/// );
+/// ```
+///
+/// The bare values can't be compared, so this doesn't compile:
+///
+/// ```compile_fail
+/// use belnapian::Belnapian;
+///
+/// let _ = Belnapian::False < Belnapian::True;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TruthOrder(pub Belnapian);
+
+/// Compares the wrapped [`Belnapian`] values in the knowledge order (see
+/// [`Belnapian::knowledge_le`]). It's a partial order: `False` and `True` are
+/// incomparable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KnowledgeOrder(pub Belnapian);
+
+/// The Hasse diagrams of both orders of Belnap's bilattice, see
+/// [`bilattice_structure`]. Every edge is a `(lower, upper)` covering pair.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BilatticeStructure {
+    pub nodes: [Belnapian; 4],
+    pub knowledge_edges: [(Belnapian, Belnapian); 4],
+    pub truth_edges: [(Belnapian, Belnapian); 4],
+}
+
+/// A binary operation over [`Belnapian`] values, precomputed as a 4×4 lookup
+/// table, see [`MemoizedOp::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MemoizedOp {
+    table: [[Belnapian; 4]; 4],
+}
+
+/// A weighted probability distribution over [`Belnapian`] values, see
+/// [`Belnapian::from_weights`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WeightedBelnapian {
+    cumulative: [f64; 4],
+}
+
+/// An iterator over the members of a [`TruthValuesPowerSet`], in declaration
+/// order (`Neither`, `False`, `True`, `Both`), see
+/// [`TruthValuesPowerSet::members`]. It does not allocate.
+#[derive(Clone, Copy, Debug)]
+pub struct MembersIter {
+    bits: u8,
+    cursor: u8,
+}
+
+/// An iterator yielding the running conjunction or disjunction of the values of
+/// another iterator, see [`TruthValueIterator`].
+#[derive(Clone, Debug)]
+pub struct LogicScan<I: Iterator> {
+    iter: I,
+    acc: Option<I::Item>,
+    op: fn(I::Item, I::Item) -> I::Item,
+}
+
 // Traits
 // -----------------------------------------------------------------------------
 
 pub trait TruthValue: Copy {}
 
+pub trait NotOp: TruthValue {
+    fn not(self) -> Self;
+}
+
 pub trait AndOp: TruthValue {
     fn and(self, other: Self) -> Self;
 }
@@ -213,7 +399,73 @@ pub trait XorOp: TruthValue {
     fn xor(self, other: Self) -> Self;
 }
 
-pub trait LogicOperand: ops::Not + AndOp + OrOp {}
+/// Implication. Each type uses its own default reading:
+///
+/// - [`Belnapian`]: material implication, `¬a ∨ b`.
+/// - [`TernaryTruth`]: Kleene's implication, `¬a ∨ b` (see
+///   [`TernaryTruth::lukasiewicz_implies`] for the Łukasiewicz one).
+/// - [`EBelnapian`]: material implication, `¬a ∨ b`, over the possible values.
+pub trait ImpliesOp: TruthValue {
+    fn implies(self, other: Self) -> Self;
+}
+
+pub trait NandOp: TruthValue {
+    fn nand(self, other: Self) -> Self;
+}
+
+pub trait NorOp: TruthValue {
+    fn nor(self, other: Self) -> Self;
+}
+
+pub trait LogicOperand: ops::Not<Output = Self> + NotOp + AndOp + OrOp + XorOp + ImpliesOp {}
+
+/// Truth value types with genuine classical `true` and `false` values.
+pub trait Classical: TruthValue {
+    const TRUE: Self;
+    const FALSE: Self;
+}
+
+/// Running logic operations over iterators of truth values, e.g. to check
+/// whether everything seen so far in a stream holds.
+///
+/// ```
+/// use belnapian::{Belnapian, TruthValueIterator};
+///
+/// let running: Vec<Belnapian> = [Belnapian::True, Belnapian::Both, Belnapian::False]
+///     .into_iter()
+///     .and_scan()
+///     .collect();
+/// assert_eq!(
+///     vec![Belnapian::True, Belnapian::Both, Belnapian::False],
+///     running
+/// );
+/// ```
+pub trait TruthValueIterator: Iterator + Sized
+where
+    Self::Item: LogicOperand,
+{
+    /// Yields the conjunction of every value up to (and including) the
+    /// current one.
+    fn and_scan(self) -> LogicScan<Self> {
+        LogicScan {
+            iter: self,
+            acc: None,
+            op: AndOp::and,
+        }
+    }
+
+    /// Yields the disjunction of every value up to (and including) the
+    /// current one.
+    fn or_scan(self) -> LogicScan<Self> {
+        LogicScan {
+            iter: self,
+            acc: None,
+            op: OrOp::or,
+        }
+    }
+}
+
+impl<I: Iterator> TruthValueIterator for I where I::Item: LogicOperand {}
 
 pub trait TruthValuesSet: Copy {
     fn could_be_neither(self) -> bool;
@@ -224,13 +476,89 @@ pub trait TruthValuesSet: Copy {
     fn is_empty(self) -> bool;
 }
 
+// Macros
+// -----------------------------------------------------------------------------
+
+/// Builds an [`Unknown`] from the truth values it could hold, listed in the
+/// canonical order `Neither, False, True, Both`.
+///
+/// ```
+/// use belnapian::{unknown, Unknown};
+///
+/// assert_eq!(Unknown::_FT_, unknown!(False, True));
+/// assert_eq!(Unknown::NFTB, unknown!(Neither, False, True, Both));
+/// ```
+///
+/// Anything that is not a valid [`Unknown`] (the empty set, singletons, or
+/// values not listed in canonical order) fails to compile:
+///
+/// ```compile_fail
+/// use belnapian::unknown;
+///
+/// let _ = unknown!(True);
+/// ```
+#[macro_export]
+macro_rules! unknown {
+    (Neither, False) => {
+        $crate::Unknown::NF__
+    };
+    (Neither, True) => {
+        $crate::Unknown::N_T_
+    };
+    (False, True) => {
+        $crate::Unknown::_FT_
+    };
+    (Neither, False, True) => {
+        $crate::Unknown::NFT_
+    };
+    (Neither, Both) => {
+        $crate::Unknown::N__B
+    };
+    (False, Both) => {
+        $crate::Unknown::_F_B
+    };
+    (Neither, False, Both) => {
+        $crate::Unknown::NF_B
+    };
+    (True, Both) => {
+        $crate::Unknown::__TB
+    };
+    (Neither, True, Both) => {
+        $crate::Unknown::N_TB
+    };
+    (False, True, Both) => {
+        $crate::Unknown::_FTB
+    };
+    (Neither, False, True, Both) => {
+        $crate::Unknown::NFTB
+    };
+    ($($values:tt)*) => {
+        compile_error!(
+            "unknown! expects between 2 and 4 distinct truth values, in the order Neither, False, True, Both"
+        )
+    };
+}
+
 // Belnapian Impls
 // -----------------------------------------------------------------------------
 
 impl TruthValue for Belnapian {}
 
+impl Classical for Belnapian {
+    const TRUE: Self = Belnapian::True;
+    const FALSE: Self = Belnapian::False;
+}
+
 impl Belnapian {
-    pub fn and(self, other: Self) -> Self {
+    /// Every value, in declaration order.
+    pub const ALL: [Belnapian; 4] = [
+        Belnapian::Neither,
+        Belnapian::False,
+        Belnapian::True,
+        Belnapian::Both,
+    ];
+
+    pub const fn and(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (Belnapian::False, _) => Belnapian::False,
@@ -245,7 +573,7 @@ impl Belnapian {
         }
     }
 
-    pub fn or(self, other: Self) -> Self {
+    pub const fn or(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (Belnapian::True, _) => Belnapian::True,
@@ -272,7 +600,7 @@ impl Belnapian {
     /// - `(a AND ¬b) OR (¬a AND b)`
     ///
     /// as it's closer to the natural language interpretation of XOR.
-    pub fn xor(self, other: Self) -> Self {
+    pub const fn xor(self, other: Self) -> Self {
         match (self, other) {
             (Belnapian::Neither, Belnapian::Both) => Belnapian::False,
             (Belnapian::Both, Belnapian::Neither) => Belnapian::False,
@@ -280,12 +608,13 @@ impl Belnapian {
             (_, Belnapian::Neither) => Belnapian::Neither,
             (Belnapian::Both, _) => Belnapian::Both,
             (_, Belnapian::Both) => Belnapian::Both,
-            (a, b) if a == b => Belnapian::False,
+            (Belnapian::False, Belnapian::False) => Belnapian::False,
+            (Belnapian::True, Belnapian::True) => Belnapian::False,
             _ => Belnapian::True,
         }
     }
 
-    pub fn not(self) -> Self {
+    pub const fn not(self) -> Self {
         match self {
             Belnapian::Neither => Belnapian::Neither,
             Belnapian::False => Belnapian::True,
@@ -294,7 +623,122 @@ impl Belnapian {
         }
     }
 
-    pub fn superposition(self, other: Self) -> Self {
+    /// Material implication, equivalent to `¬a ∨ b`. This is the Belnap
+    /// reading of `→`; it is not the only option (e.g. it doesn't make `a → a`
+    /// designated when `a` is `Neither`), but it's the one that keeps the
+    /// usual equivalences with `not` and `or`.
+    pub const fn implies(self, other: Self) -> Self {
+        match (self, other) {
+            (Belnapian::Neither, Belnapian::Neither) => Belnapian::Neither,
+            (Belnapian::Neither, Belnapian::False) => Belnapian::Neither,
+            (Belnapian::Neither, Belnapian::True) => Belnapian::True,
+            (Belnapian::Neither, Belnapian::Both) => Belnapian::True,
+            (Belnapian::False, Belnapian::Neither) => Belnapian::True,
+            (Belnapian::False, Belnapian::False) => Belnapian::True,
+            (Belnapian::False, Belnapian::True) => Belnapian::True,
+            (Belnapian::False, Belnapian::Both) => Belnapian::True,
+            (Belnapian::True, Belnapian::Neither) => Belnapian::Neither,
+            (Belnapian::True, Belnapian::False) => Belnapian::False,
+            (Belnapian::True, Belnapian::True) => Belnapian::True,
+            (Belnapian::True, Belnapian::Both) => Belnapian::Both,
+            (Belnapian::Both, Belnapian::Neither) => Belnapian::True,
+            (Belnapian::Both, Belnapian::False) => Belnapian::Both,
+            (Belnapian::Both, Belnapian::True) => Belnapian::True,
+            (Belnapian::Both, Belnapian::Both) => Belnapian::Both,
+        }
+    }
+
+    /// Contrapositive of the material implication, `¬b → ¬a`.
+    ///
+    /// It never diverges from [`Belnapian::implies`]: `¬b → ¬a` unfolds to
+    /// `¬¬b ∨ ¬a`, and since `not` is an involution (it swaps `False` and `True`
+    /// while fixing `Neither` and `Both`) and `or` is commutative, that is
+    /// `¬a ∨ b` for all 16 pairs, including those involving `Neither` and `Both`.
+    pub const fn contrapositive(self, other: Self) -> Self {
+        other.not().implies(self.not())
+    }
+
+    /// Material biconditional, defined as `(a → b) ∧ (b → a)`.
+    pub const fn iff(self, other: Self) -> Self {
+        self.implies(other).and(other.implies(self))
+    }
+
+    /// Equivalent to `¬(a ∧ b)`.
+    pub const fn nand(self, other: Self) -> Self {
+        match (self, other) {
+            (Belnapian::Neither, Belnapian::Neither) => Belnapian::Neither,
+            (Belnapian::Neither, Belnapian::False) => Belnapian::True,
+            (Belnapian::Neither, Belnapian::True) => Belnapian::Neither,
+            (Belnapian::Neither, Belnapian::Both) => Belnapian::True,
+            (Belnapian::False, Belnapian::Neither) => Belnapian::True,
+            (Belnapian::False, Belnapian::False) => Belnapian::True,
+            (Belnapian::False, Belnapian::True) => Belnapian::True,
+            (Belnapian::False, Belnapian::Both) => Belnapian::True,
+            (Belnapian::True, Belnapian::Neither) => Belnapian::Neither,
+            (Belnapian::True, Belnapian::False) => Belnapian::True,
+            (Belnapian::True, Belnapian::True) => Belnapian::False,
+            (Belnapian::True, Belnapian::Both) => Belnapian::Both,
+            (Belnapian::Both, Belnapian::Neither) => Belnapian::True,
+            (Belnapian::Both, Belnapian::False) => Belnapian::True,
+            (Belnapian::Both, Belnapian::True) => Belnapian::Both,
+            (Belnapian::Both, Belnapian::Both) => Belnapian::Both,
+        }
+    }
+
+    /// Equivalent to `¬(a ∨ b)`.
+    pub const fn nor(self, other: Self) -> Self {
+        match (self, other) {
+            (Belnapian::Neither, Belnapian::Neither) => Belnapian::Neither,
+            (Belnapian::Neither, Belnapian::False) => Belnapian::Neither,
+            (Belnapian::Neither, Belnapian::True) => Belnapian::False,
+            (Belnapian::Neither, Belnapian::Both) => Belnapian::False,
+            (Belnapian::False, Belnapian::Neither) => Belnapian::Neither,
+            (Belnapian::False, Belnapian::False) => Belnapian::True,
+            (Belnapian::False, Belnapian::True) => Belnapian::False,
+            (Belnapian::False, Belnapian::Both) => Belnapian::Both,
+            (Belnapian::True, Belnapian::Neither) => Belnapian::False,
+            (Belnapian::True, Belnapian::False) => Belnapian::False,
+            (Belnapian::True, Belnapian::True) => Belnapian::False,
+            (Belnapian::True, Belnapian::Both) => Belnapian::False,
+            (Belnapian::Both, Belnapian::Neither) => Belnapian::False,
+            (Belnapian::Both, Belnapian::False) => Belnapian::Both,
+            (Belnapian::Both, Belnapian::True) => Belnapian::False,
+            (Belnapian::Both, Belnapian::Both) => Belnapian::Both,
+        }
+    }
+
+    /// Belnap's negation, built only from [`Belnapian::nand`]: `a ⊼ a`. It works
+    /// because `∧` is idempotent.
+    ///
+    /// Together with [`Belnapian::and_via_nand`] and
+    /// [`Belnapian::or_via_nand`], this shows that `nand` alone generates the
+    /// `{∧, ∨, ¬}` fragment of the logic, so circuits restricted to `nand`
+    /// gates can express any formula built from those connectives. Note that
+    /// this fragment is not functionally complete over the four values (e.g.
+    /// its formulas always evaluate to `Neither` when every input is
+    /// `Neither`).
+    pub const fn not_via_nand(self) -> Self {
+        self.nand(self)
+    }
+
+    /// Belnap's conjunction, built only from [`Belnapian::nand`]:
+    /// `(a ⊼ b) ⊼ (a ⊼ b)`. See [`Belnapian::not_via_nand`].
+    pub const fn and_via_nand(self, other: Self) -> Self {
+        let nand = self.nand(other);
+        nand.nand(nand)
+    }
+
+    /// Belnap's disjunction, built only from [`Belnapian::nand`]:
+    /// `(a ⊼ a) ⊼ (b ⊼ b)`. See [`Belnapian::not_via_nand`].
+    pub const fn or_via_nand(self, other: Self) -> Self {
+        self.nand(self).nand(other.nand(other))
+    }
+
+    /// The join (least upper bound) of the knowledge order of Belnap's
+    /// bilattice, also known as "gullibility": it accepts the evidence of both
+    /// operands, so `True ⊕ False = Both`. `Neither` is its identity, and
+    /// `Both` is absorbing.
+    pub const fn superposition(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (Belnapian::Both, _) => Belnapian::Both,
@@ -309,7 +753,11 @@ impl Belnapian {
         }
     }
 
-    pub fn annihilation(self, other: Self) -> Self {
+    /// The meet (greatest lower bound) of the knowledge order of Belnap's
+    /// bilattice, also known as "consensus": it keeps only the evidence shared
+    /// by both operands, so `True ⊗ False = Neither`. `Both` is its identity,
+    /// and `Neither` is absorbing.
+    pub const fn annihilation(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (Belnapian::Neither, _) => Belnapian::Neither,
@@ -323,6 +771,409 @@ impl Belnapian {
             (Belnapian::Both, Belnapian::Both) => Belnapian::Both,
         }
     }
+
+    /// Whether `self ≤t other` in the truth order, where `False` is the bottom,
+    /// `True` is the top, and `Neither` and `Both` are incomparable.
+    pub const fn truth_le(self, other: Self) -> bool {
+        self.and(other).eq_const(self)
+    }
+
+    /// Greatest lower bound in the truth order, the same as [`Belnapian::and`].
+    pub const fn truth_meet(self, other: Self) -> Self {
+        self.and(other)
+    }
+
+    /// Least upper bound in the truth order, the same as [`Belnapian::or`].
+    pub const fn truth_join(self, other: Self) -> Self {
+        self.or(other)
+    }
+
+    /// Whether `self ≤k other` in the knowledge order, where `Neither` is the
+    /// bottom, `Both` is the top, and `False` and `True` are incomparable.
+    pub const fn knowledge_le(self, other: Self) -> bool {
+        self.superposition(other).eq_const(other)
+    }
+
+    /// Greatest lower bound in the knowledge order, the same as
+    /// [`Belnapian::annihilation`].
+    pub const fn knowledge_meet(self, other: Self) -> Self {
+        self.annihilation(other)
+    }
+
+    /// Least upper bound in the knowledge order, the same as
+    /// [`Belnapian::superposition`].
+    pub const fn knowledge_join(self, other: Self) -> Self {
+        self.superposition(other)
+    }
+
+    /// Identity connective: whether both operands are the same truth value.
+    ///
+    /// This is the known/known case of [`Unknown::eq`] and of the mixed
+    /// `EBelnapian` tables: the question "are they the same value?" always has
+    /// a classical answer once both values are known, even for `Neither` and
+    /// `Both` (so `Neither.eq(Neither)` is `True`, and `Neither.eq(Both)` is
+    /// `False`). For the informational equivalence `(a → b) ∧ (b → a)`, see
+    /// [`Belnapian::iff`].
+    pub const fn eq(self, other: Self) -> Self {
+        match (self, other) {
+            (Belnapian::Neither, Belnapian::Neither) => Belnapian::True,
+            (Belnapian::False, Belnapian::False) => Belnapian::True,
+            (Belnapian::True, Belnapian::True) => Belnapian::True,
+            (Belnapian::Both, Belnapian::Both) => Belnapian::True,
+            _ => Belnapian::False,
+        }
+    }
+
+    /// Structural equality (the same as `==`), usable in `const` contexts.
+    pub const fn eq_const(self, other: Self) -> bool {
+        self as u8 == other as u8
+    }
+
+    /// Structural equality: whether both are the same truth value, as a plain
+    /// `bool`. There are three ways of comparing two values:
+    ///
+    /// - `a == b` and `a.classical_eq(b)` are structural, and return a `bool`
+    ///   (`classical_eq` is also usable in `const` contexts, like
+    ///   [`Belnapian::eq_const`]).
+    /// - [`Belnapian::eq`] is the identity connective: the same answer, but as
+    ///   a truth value (`True` or `False`), so it can be combined with the
+    ///   other connectives.
+    /// - [`Belnapian::iff`] is the logical equivalence `(a → b) ∧ (b → a)`,
+    ///   which can be any of the four values (e.g. `Neither.iff(Neither)` is
+    ///   `Neither`).
+    pub const fn classical_eq(self, other: Self) -> bool {
+        self.eq_const(other)
+    }
+
+    /// Splits the value into two channels: `(told_true, told_false)`.
+    ///
+    /// - `Neither` is `(False, False)`
+    /// - `False` is `(False, True)`
+    /// - `True` is `(True, False)`
+    /// - `Both` is `(True, True)`
+    ///
+    /// The channels never hold `TernaryTruth::Unknown`.
+    pub const fn to_channels(self) -> (TernaryTruth, TernaryTruth) {
+        match self {
+            Belnapian::Neither => (TernaryTruth::False, TernaryTruth::False),
+            Belnapian::False => (TernaryTruth::False, TernaryTruth::True),
+            Belnapian::True => (TernaryTruth::True, TernaryTruth::False),
+            Belnapian::Both => (TernaryTruth::True, TernaryTruth::True),
+        }
+    }
+
+    /// Inverse of [`Belnapian::to_channels`]. Fails with
+    /// [`ConversionError::NotClassical`] if any channel is `Unknown`.
+    pub fn from_channels(
+        told_true: TernaryTruth,
+        told_false: TernaryTruth,
+    ) -> Result<Self, ConversionError> {
+        match (told_true, told_false) {
+            (TernaryTruth::False, TernaryTruth::False) => Ok(Belnapian::Neither),
+            (TernaryTruth::False, TernaryTruth::True) => Ok(Belnapian::False),
+            (TernaryTruth::True, TernaryTruth::False) => Ok(Belnapian::True),
+            (TernaryTruth::True, TernaryTruth::True) => Ok(Belnapian::Both),
+            _ => Err(ConversionError::NotClassical),
+        }
+    }
+
+    /// Removes the "told true" evidence: `Both → False`, `True → Neither`, and
+    /// leaves `False` and `Neither` unchanged.
+    pub const fn retract_true(self) -> Self {
+        match self {
+            Belnapian::Both => Belnapian::False,
+            Belnapian::True => Belnapian::Neither,
+            value => value,
+        }
+    }
+
+    /// Removes the "told false" evidence: `Both → True`, `False → Neither`, and
+    /// leaves `True` and `Neither` unchanged.
+    pub const fn retract_false(self) -> Self {
+        match self {
+            Belnapian::Both => Belnapian::True,
+            Belnapian::False => Belnapian::Neither,
+            value => value,
+        }
+    }
+
+    /// Returns the level of the value in the truth order, counted as the
+    /// distance above its bottom `False`: `False = 0`, `Neither = Both = 1`,
+    /// and `True = 2`.
+    pub const fn truth_height(self) -> u8 {
+        match self {
+            Belnapian::False => 0,
+            Belnapian::Neither | Belnapian::Both => 1,
+            Belnapian::True => 2,
+        }
+    }
+
+    /// Returns the level of the value in the knowledge order, counted as the
+    /// distance above its bottom `Neither`: `Neither = 0`, `False = True = 1`,
+    /// and `Both = 2`.
+    pub const fn knowledge_height(self) -> u8 {
+        match self {
+            Belnapian::Neither => 0,
+            Belnapian::False | Belnapian::True => 1,
+            Belnapian::Both => 2,
+        }
+    }
+
+    /// Builds a probability distribution over the values from (non-normalized)
+    /// weights, indexed as `[Neither, False, True, Both]`. Returns `None` if any
+    /// weight is negative or not finite, or if all of them are zero.
+    pub fn from_weights(weights: [f64; 4]) -> Option<WeightedBelnapian> {
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return None;
+        }
+
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 || !total.is_finite() {
+            return None;
+        }
+
+        let mut accumulated = 0.0;
+        Some(WeightedBelnapian {
+            cumulative: weights.map(|w| {
+                accumulated += w;
+                accumulated / total
+            }),
+        })
+    }
+
+    /// Whether the value is designated, that is, whether it counts as "holding"
+    /// when checking validity. The designated values of Belnap's logic are the
+    /// ones that are told true: `True` and `Both`.
+    pub const fn is_designated(self) -> bool {
+        match self {
+            Belnapian::True | Belnapian::Both => true,
+            Belnapian::Neither | Belnapian::False => false,
+        }
+    }
+
+    /// Whether the value is a member of a custom set of designated values,
+    /// e.g. `__T_` for a stricter notion of validity than
+    /// [`Belnapian::is_designated`].
+    pub fn is_designated_in(self, designated: TruthValuesPowerSet) -> bool {
+        designated.contains(self)
+    }
+
+    /// Majority vote over the given values. Every `True` or `Both` counts as a
+    /// vote for true, every `False` or `Both` counts as a vote for false, and
+    /// `Neither` doesn't vote. The result is:
+    ///
+    /// - `Neither` if nobody voted (including when `values` is empty).
+    /// - `True` or `False` if it got strictly more votes than the other one.
+    /// - `Both` on a tie between true and false votes.
+    pub fn vote(values: &[Belnapian]) -> Belnapian {
+        let (true_votes, false_votes) =
+            values
+                .iter()
+                .fold((0usize, 0usize), |(t, f), value| match value {
+                    Belnapian::Neither => (t, f),
+                    Belnapian::False => (t, f + 1),
+                    Belnapian::True => (t + 1, f),
+                    Belnapian::Both => (t + 1, f + 1),
+                });
+
+        match true_votes.cmp(&false_votes) {
+            core::cmp::Ordering::Greater => Belnapian::True,
+            core::cmp::Ordering::Less => Belnapian::False,
+            core::cmp::Ordering::Equal if true_votes == 0 => Belnapian::Neither,
+            core::cmp::Ordering::Equal => Belnapian::Both,
+        }
+    }
+
+    /// Rounds the value up to the nearest designated value (`True` or `Both`)
+    /// in the knowledge order. The policy is to add the minimal evidence
+    /// needed: the "told true" channel is set and the "told false" channel is
+    /// kept, so `Neither → True`, `False → Both`, and designated values are
+    /// left unchanged.
+    pub const fn round_to_designated(self) -> Self {
+        match self {
+            Belnapian::Neither => Belnapian::True,
+            Belnapian::False => Belnapian::Both,
+            Belnapian::True => Belnapian::True,
+            Belnapian::Both => Belnapian::Both,
+        }
+    }
+
+    /// Belnap's conflation, the knowledge order counterpart of negation: it
+    /// swaps `Neither ↔ Both` and leaves `False` and `True` unchanged. It
+    /// commutes with [`Belnapian::not`].
+    pub const fn conflate(self) -> Self {
+        match self {
+            Belnapian::Neither => Belnapian::Both,
+            Belnapian::False => Belnapian::False,
+            Belnapian::True => Belnapian::True,
+            Belnapian::Both => Belnapian::Neither,
+        }
+    }
+
+    /// Maps the value through the given [`Symmetry`].
+    pub const fn apply_symmetry(self, sym: Symmetry) -> Self {
+        match (sym, self) {
+            (Symmetry::Identity, value) => value,
+            (Symmetry::Negation, Belnapian::False) => Belnapian::True,
+            (Symmetry::Negation, Belnapian::True) => Belnapian::False,
+            (Symmetry::Negation, value) => value,
+            (Symmetry::Conflation, Belnapian::Neither) => Belnapian::Both,
+            (Symmetry::Conflation, Belnapian::Both) => Belnapian::Neither,
+            (Symmetry::Conflation, value) => value,
+            (Symmetry::NegationConflation, Belnapian::Neither) => Belnapian::Both,
+            (Symmetry::NegationConflation, Belnapian::False) => Belnapian::True,
+            (Symmetry::NegationConflation, Belnapian::True) => Belnapian::False,
+            (Symmetry::NegationConflation, Belnapian::Both) => Belnapian::Neither,
+        }
+    }
+
+    /// Counts how many values support truth (`True` or `Both`) and how many
+    /// reject it (`False` or `Both`), returned as `(support, rejection)`. Every
+    /// `Both` counts towards both numbers, and every `Neither` towards none.
+    pub fn support_rejection(values: &[Belnapian]) -> (usize, usize) {
+        values
+            .iter()
+            .fold((0, 0), |(support, rejection), value| match value {
+                Belnapian::Neither => (support, rejection),
+                Belnapian::False => (support, rejection + 1),
+                Belnapian::True => (support + 1, rejection),
+                Belnapian::Both => (support + 1, rejection + 1),
+            })
+    }
+
+    /// Computes the conjunction of all the values, starting from its identity
+    /// `True` (so an empty iterator gives `True`). Stops at the first `False`,
+    /// which is absorbing.
+    pub fn reduce_and(values: impl IntoIterator<Item = Belnapian>) -> Belnapian {
+        fold_with_absorbing(
+            values,
+            Belnapian::and,
+            Belnapian::True,
+            Some(Belnapian::False),
+        )
+    }
+
+    /// Computes the disjunction of all the values, starting from its identity
+    /// `False` (so an empty iterator gives `False`). Stops at the first `True`,
+    /// which is absorbing.
+    pub fn reduce_or(values: impl IntoIterator<Item = Belnapian>) -> Belnapian {
+        fold_with_absorbing(
+            values,
+            Belnapian::or,
+            Belnapian::False,
+            Some(Belnapian::True),
+        )
+    }
+
+    /// Computes the superposition (knowledge join) of all the values, starting
+    /// from its identity `Neither`. Stops as soon as it reaches `Both`, which is
+    /// absorbing.
+    pub fn reduce_superposition(values: impl IntoIterator<Item = Belnapian>) -> Belnapian {
+        fold_with_absorbing(
+            values,
+            Belnapian::superposition,
+            Belnapian::Neither,
+            Some(Belnapian::Both),
+        )
+    }
+
+    /// Computes the annihilation (knowledge meet) of all the values, starting
+    /// from its identity `Both`. Stops as soon as it reaches `Neither`, which is
+    /// absorbing.
+    pub fn reduce_annihilation(values: impl IntoIterator<Item = Belnapian>) -> Belnapian {
+        fold_with_absorbing(
+            values,
+            Belnapian::annihilation,
+            Belnapian::Both,
+            Some(Belnapian::Neither),
+        )
+    }
+
+    /// Returns the stable discriminant of the value, see [`Belnapian`].
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Inverse of [`Belnapian::as_u8`]. Returns `None` for values above `3`.
+    pub const fn from_u8(value: u8) -> Option<Belnapian> {
+        match value {
+            0 => Some(Belnapian::Neither),
+            1 => Some(Belnapian::False),
+            2 => Some(Belnapian::True),
+            3 => Some(Belnapian::Both),
+            _ => None,
+        }
+    }
+
+    /// Packs the values into bytes, 4 values per byte, using the layout
+    /// described in [`BelnapianSlice`] (the value at index `i` lives in the
+    /// bits `2 * (i % 4)` and `2 * (i % 4) + 1` of byte `i / 4`). Unused lanes
+    /// of the trailing byte are left as `0b00`.
+    #[cfg(feature = "alloc")]
+    pub fn pack(values: &[Belnapian]) -> Vec<u8> {
+        let mut bytes = vec![0u8; values.len().div_ceil(4)];
+        for (i, value) in values.iter().enumerate() {
+            bytes[i / 4] |= (*value as u8) << (2 * (i % 4));
+        }
+        bytes
+    }
+
+    /// Unpacks the first `count` values from bytes produced by
+    /// [`Belnapian::pack`]. Returns `None` if `bytes` is too short to hold
+    /// `count` values.
+    #[cfg(feature = "alloc")]
+    pub fn unpack(bytes: &[u8], count: usize) -> Option<Vec<Belnapian>> {
+        Some(BelnapianSlice::new(bytes, count)?.iter().collect())
+    }
+
+    /// Counts the binary functions over the four values (out of the `4¹⁶` of
+    /// them) that map every `(a, b)` input of `constraints` to its output.
+    ///
+    /// Every cell of the 4×4 table is independent: a constrained cell has a
+    /// single choice, and an unconstrained one has four. The count is `0` if
+    /// two constraints give different outputs for the same input. It's
+    /// returned as a `u64`, since `4¹⁶` doesn't fit in a 32-bit `usize`.
+    pub fn functions_matching(constraints: &[((Belnapian, Belnapian), Belnapian)]) -> u64 {
+        let mut cells: [Option<Belnapian>; 16] = [None; 16];
+        for &((a, b), output) in constraints {
+            let cell = &mut cells[a as usize * 4 + b as usize];
+            match cell {
+                Some(previous) if *previous != output => return 0,
+                _ => *cell = Some(output),
+            }
+        }
+
+        let free_cells = cells.iter().filter(|cell| cell.is_none()).count();
+        4u64.pow(free_cells as u32)
+    }
+
+    /// Returns the built-in connectives that "notice" the difference between
+    /// `a` and `b`, that is, those for which `op(a, x) != op(b, x)` for some `x`.
+    #[cfg(feature = "alloc")]
+    pub fn distinguishing_ops(a: Belnapian, b: Belnapian) -> Vec<Connective> {
+        Connective::ALL
+            .into_iter()
+            .filter(|op| {
+                Belnapian::ALL
+                    .iter()
+                    .any(|x| op.apply(a, *x) != op.apply(b, *x))
+            })
+            .collect()
+    }
+
+    /// Applies every binary connective to the pair `(self, other)` at once.
+    pub fn all_ops(self, other: Self) -> BinaryResults {
+        BinaryResults {
+            and: self.and(other),
+            or: self.or(other),
+            xor: self.xor(other),
+            implies: self.implies(other),
+            iff: self.iff(other),
+            superposition: self.superposition(other),
+            annihilation: self.annihilation(other),
+            eq: self.eq(other),
+        }
+    }
 }
 
 impl AndOp for Belnapian {
@@ -355,6 +1206,30 @@ impl XorOp for Belnapian {
     }
 }
 
+impl ImpliesOp for Belnapian {
+    fn implies(self, other: Self) -> Self {
+        self.implies(other)
+    }
+}
+
+impl NandOp for Belnapian {
+    fn nand(self, other: Self) -> Self {
+        self.nand(other)
+    }
+}
+
+impl NorOp for Belnapian {
+    fn nor(self, other: Self) -> Self {
+        self.nor(other)
+    }
+}
+
+impl NotOp for Belnapian {
+    fn not(self) -> Self {
+        self.not()
+    }
+}
+
 impl ops::Not for Belnapian {
     type Output = Self;
 
@@ -363,15 +1238,211 @@ impl ops::Not for Belnapian {
     }
 }
 
+/// `a >> b` is the logical implication `a.implies(b)` (material
+/// implication), **not** a bit shift.
+impl ops::Shr for Belnapian {
+    type Output = Self;
+
+    fn shr(self, other: Self) -> Self {
+        self.implies(other)
+    }
+}
+
 impl LogicOperand for Belnapian {}
 
+// Connective Impls
+// -----------------------------------------------------------------------------
+
+impl Connective {
+    pub const ALL: [Connective; 9] = [
+        Connective::And,
+        Connective::Or,
+        Connective::Xor,
+        Connective::Implies,
+        Connective::Iff,
+        Connective::Nand,
+        Connective::Nor,
+        Connective::Superposition,
+        Connective::Annihilation,
+    ];
+
+    pub const fn apply(self, a: Belnapian, b: Belnapian) -> Belnapian {
+        match self {
+            Connective::And => a.and(b),
+            Connective::Or => a.or(b),
+            Connective::Xor => a.xor(b),
+            Connective::Implies => a.implies(b),
+            Connective::Iff => a.iff(b),
+            Connective::Nand => a.nand(b),
+            Connective::Nor => a.nor(b),
+            Connective::Superposition => a.superposition(b),
+            Connective::Annihilation => a.annihilation(b),
+        }
+    }
+}
+
+// Bilattice Orders Impls
+// -----------------------------------------------------------------------------
+
+impl PartialOrd for TruthOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self.0.truth_le(other.0), other.0.truth_le(self.0)) {
+            (true, true) => Some(core::cmp::Ordering::Equal),
+            (true, false) => Some(core::cmp::Ordering::Less),
+            (false, true) => Some(core::cmp::Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+impl PartialOrd for KnowledgeOrder {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self.0.knowledge_le(other.0), other.0.knowledge_le(self.0)) {
+            (true, true) => Some(core::cmp::Ordering::Equal),
+            (true, false) => Some(core::cmp::Ordering::Less),
+            (false, true) => Some(core::cmp::Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+// MemoizedOp Impls
+// -----------------------------------------------------------------------------
+
+impl MemoizedOp {
+    /// Evaluates `op` once for each of the 16 pairs of values.
+    pub fn new(op: impl Fn(Belnapian, Belnapian) -> Belnapian) -> Self {
+        MemoizedOp {
+            table: Belnapian::ALL.map(|a| Belnapian::ALL.map(|b| op(a, b))),
+        }
+    }
+
+    pub fn apply(&self, a: Belnapian, b: Belnapian) -> Belnapian {
+        self.table[a as usize][b as usize]
+    }
+}
+
+// WeightedBelnapian Impls
+// -----------------------------------------------------------------------------
+
+impl WeightedBelnapian {
+    /// Returns the probability of every value, indexed as
+    /// `[Neither, False, True, Both]`.
+    pub fn probabilities(&self) -> [f64; 4] {
+        let mut previous = 0.0;
+        self.cumulative.map(|c| {
+            let p = c - previous;
+            previous = c;
+            p
+        })
+    }
+
+    /// Maps a uniform sample `u` in `[0, 1)` to a value, so any source of
+    /// randomness can be used to draw from the distribution. Values of `u`
+    /// outside of that range are clamped.
+    ///
+    /// ```
+    /// use belnapian::Belnapian;
+    ///
+    /// let noisy = Belnapian::from_weights([0.0, 1.0, 3.0, 0.0]).unwrap();
+    /// assert_eq!(Belnapian::False, noisy.sample(0.2));
+    /// assert_eq!(Belnapian::True, noisy.sample(0.3));
+    /// ```
+    pub fn sample(&self, u: f64) -> Belnapian {
+        let u = u.max(0.0);
+        Belnapian::ALL
+            .into_iter()
+            .zip(self.cumulative)
+            .find(|&(_, c)| u < c)
+            .map_or(self.last_possible(), |(value, _)| value)
+    }
+
+    fn last_possible(&self) -> Belnapian {
+        let mut last = Belnapian::Neither;
+        let mut previous = 0.0;
+        for (value, c) in Belnapian::ALL.into_iter().zip(self.cumulative) {
+            if c > previous {
+                last = value;
+            }
+            previous = c;
+        }
+        last
+    }
+}
+
+// Packed Belnapian Views
+// -----------------------------------------------------------------------------
+
+/// A read-only view over [`Belnapian`] values packed in 2-bit lanes.
+///
+/// The value at index `i` lives in byte `i / 4`, at bits `2 * (i % 4)` (the
+/// "told false" bit) and `2 * (i % 4) + 1` (the "told true" bit), so:
+/// - `Neither` is `0b00`
+/// - `False` is `0b01`
+/// - `True` is `0b10`
+/// - `Both` is `0b11`
+#[derive(Clone, Copy, Debug)]
+pub struct BelnapianSlice<'a> {
+    bytes: &'a [u8],
+    len: usize,
+}
+
+impl<'a> BelnapianSlice<'a> {
+    /// Creates a view over the first `len` values packed in `bytes`. Returns
+    /// `None` if `bytes` is too short to hold them.
+    pub fn new(bytes: &'a [u8], len: usize) -> Option<Self> {
+        if len > bytes.len() * 4 {
+            return None;
+        }
+        Some(BelnapianSlice { bytes, len })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<Belnapian> {
+        if index >= self.len {
+            return None;
+        }
+
+        match (self.bytes[index / 4] >> (2 * (index % 4))) & 0b11 {
+            0b00 => Some(Belnapian::Neither),
+            0b01 => Some(Belnapian::False),
+            0b10 => Some(Belnapian::True),
+            _ => Some(Belnapian::Both),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Belnapian> + 'a {
+        let view = *self;
+        (0..self.len).filter_map(move |index| view.get(index))
+    }
+}
+
 // TernaryTruth Impls
 // -----------------------------------------------------------------------------
 
 impl TruthValue for TernaryTruth {}
 
+impl Classical for TernaryTruth {
+    const TRUE: Self = TernaryTruth::True;
+    const FALSE: Self = TernaryTruth::False;
+}
+
 impl TernaryTruth {
-    pub fn and(self, other: Self) -> Self {
+    /// Every value, in declaration order.
+    pub const ALL: [TernaryTruth; 3] = [
+        TernaryTruth::False,
+        TernaryTruth::True,
+        TernaryTruth::Unknown,
+    ];
+
+    pub const fn and(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (TernaryTruth::False, _) => TernaryTruth::False,
@@ -382,7 +1453,7 @@ impl TernaryTruth {
         }
     }
 
-    pub fn or(self, other: Self) -> Self {
+    pub const fn or(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (TernaryTruth::True, _) => TernaryTruth::True,
@@ -393,33 +1464,107 @@ impl TernaryTruth {
         }
     }
 
-    pub fn xor(self, other: Self) -> Self {
+    pub const fn xor(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (TernaryTruth::Unknown, _) => TernaryTruth::Unknown,
             (_, TernaryTruth::Unknown) => TernaryTruth::Unknown,
             (TernaryTruth::True, TernaryTruth::True) => TernaryTruth::False,
-            (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::False,
-            _ => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::False,
+            _ => TernaryTruth::True,
+        }
+    }
+
+    pub const fn not(self) -> Self {
+        match self {
+            TernaryTruth::False => TernaryTruth::True,
+            TernaryTruth::True => TernaryTruth::False,
+            TernaryTruth::Unknown => TernaryTruth::Unknown,
+        }
+    }
+
+    /// Kleene implication, equivalent to `¬a ∨ b`.
+    pub const fn implies(self, other: Self) -> Self {
+        match (self, other) {
+            (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::Unknown) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::True) => TernaryTruth::True,
+            (TernaryTruth::Unknown, TernaryTruth::False) => TernaryTruth::Unknown,
+            (TernaryTruth::Unknown, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::Unknown, TernaryTruth::True) => TernaryTruth::True,
+            (TernaryTruth::True, TernaryTruth::False) => TernaryTruth::False,
+            (TernaryTruth::True, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::True, TernaryTruth::True) => TernaryTruth::True,
+        }
+    }
+
+    /// Łukasiewicz implication. It only differs from the Kleene one
+    /// ([`TernaryTruth::implies`]) in the `Unknown → Unknown` cell, which is
+    /// `True` here: reading `Unknown` as the value `1/2`, `a → b` is
+    /// `min(1, 1 - a + b)`, so any value implies itself. This makes `a → a` a
+    /// tautology, at the cost of `a → b` no longer being `¬a ∨ b`.
+    pub const fn lukasiewicz_implies(self, other: Self) -> Self {
+        match (self, other) {
+            (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::Unknown) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::True) => TernaryTruth::True,
+            (TernaryTruth::Unknown, TernaryTruth::False) => TernaryTruth::Unknown,
+            (TernaryTruth::Unknown, TernaryTruth::Unknown) => TernaryTruth::True,
+            (TernaryTruth::Unknown, TernaryTruth::True) => TernaryTruth::True,
+            (TernaryTruth::True, TernaryTruth::False) => TernaryTruth::False,
+            (TernaryTruth::True, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::True, TernaryTruth::True) => TernaryTruth::True,
+        }
+    }
+
+    /// Equivalent to `¬(a ∧ b)`.
+    pub const fn nand(self, other: Self) -> Self {
+        match (self, other) {
+            (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::Unknown) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::True) => TernaryTruth::True,
+            (TernaryTruth::Unknown, TernaryTruth::False) => TernaryTruth::True,
+            (TernaryTruth::Unknown, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::Unknown, TernaryTruth::True) => TernaryTruth::Unknown,
+            (TernaryTruth::True, TernaryTruth::False) => TernaryTruth::True,
+            (TernaryTruth::True, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::True, TernaryTruth::True) => TernaryTruth::False,
+        }
+    }
+
+    /// Equivalent to `¬(a ∨ b)`.
+    pub const fn nor(self, other: Self) -> Self {
+        match (self, other) {
+            (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::True,
+            (TernaryTruth::False, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::False, TernaryTruth::True) => TernaryTruth::False,
+            (TernaryTruth::Unknown, TernaryTruth::False) => TernaryTruth::Unknown,
+            (TernaryTruth::Unknown, TernaryTruth::Unknown) => TernaryTruth::Unknown,
+            (TernaryTruth::Unknown, TernaryTruth::True) => TernaryTruth::False,
+            (TernaryTruth::True, TernaryTruth::False) => TernaryTruth::False,
+            (TernaryTruth::True, TernaryTruth::Unknown) => TernaryTruth::False,
+            (TernaryTruth::True, TernaryTruth::True) => TernaryTruth::False,
         }
     }
 
-    pub fn not(self) -> Self {
+    pub const fn is_unknown(self) -> bool {
         match self {
-            TernaryTruth::False => TernaryTruth::True,
-            TernaryTruth::True => TernaryTruth::False,
-            TernaryTruth::Unknown => TernaryTruth::Unknown,
+            TernaryTruth::Unknown => true,
+            _ => false,
         }
     }
 
-    pub fn is_unknown(self) -> bool {
+    /// Whether the value is designated. The only designated value of Kleene's
+    /// logic is `True`, see [`Belnapian::is_designated`].
+    pub const fn is_designated(self) -> bool {
         match self {
-            TernaryTruth::Unknown => true,
+            TernaryTruth::True => true,
             _ => false,
         }
     }
 
-    pub fn eq(self, other: Self) -> Self {
+    /// Logical equivalence. For the structural comparison, use `==` instead.
+    pub const fn eq(self, other: Self) -> Self {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
             (TernaryTruth::False, TernaryTruth::False) => TernaryTruth::True,
@@ -429,6 +1574,58 @@ impl TernaryTruth {
             _ => TernaryTruth::False,
         }
     }
+
+    /// Structural equality (the same as `==`), usable in `const` contexts.
+    pub const fn eq_const(self, other: Self) -> bool {
+        self as u8 == other as u8
+    }
+
+    /// Packs the values into bytes, 4 values per byte, using the layout
+    /// described in [`ternary_and_packed`]. Unused lanes of the trailing byte
+    /// are left as `0b00`.
+    #[cfg(feature = "alloc")]
+    pub fn pack(values: &[TernaryTruth]) -> Vec<u8> {
+        let mut bytes = vec![0u8; values.len().div_ceil(4)];
+        for (i, value) in values.iter().enumerate() {
+            bytes[i / 4] |= value.to_packed_lane() << (2 * (i % 4));
+        }
+        bytes
+    }
+
+    /// Unpacks the first `count` values from bytes produced by
+    /// [`TernaryTruth::pack`] (or by the packed operations).
+    ///
+    /// Returns `None` if `bytes` is too short to hold `count` values, or if any
+    /// of those lanes holds the invalid `0b00` pattern.
+    #[cfg(feature = "alloc")]
+    pub fn unpack(bytes: &[u8], count: usize) -> Option<Vec<TernaryTruth>> {
+        if count > bytes.len() * 4 {
+            return None;
+        }
+
+        (0..count)
+            .map(|i| TernaryTruth::from_packed_lane(bytes[i / 4] >> (2 * (i % 4))))
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_packed_lane(self) -> u8 {
+        match self {
+            TernaryTruth::False => 0b01,
+            TernaryTruth::True => 0b10,
+            TernaryTruth::Unknown => 0b11,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn from_packed_lane(lane: u8) -> Option<TernaryTruth> {
+        match lane & 0b11 {
+            0b01 => Some(TernaryTruth::False),
+            0b10 => Some(TernaryTruth::True),
+            0b11 => Some(TernaryTruth::Unknown),
+            _ => None,
+        }
+    }
 }
 
 impl AndOp for TernaryTruth {
@@ -449,6 +1646,30 @@ impl XorOp for TernaryTruth {
     }
 }
 
+impl ImpliesOp for TernaryTruth {
+    fn implies(self, other: Self) -> Self {
+        self.implies(other)
+    }
+}
+
+impl NandOp for TernaryTruth {
+    fn nand(self, other: Self) -> Self {
+        self.nand(other)
+    }
+}
+
+impl NorOp for TernaryTruth {
+    fn nor(self, other: Self) -> Self {
+        self.nor(other)
+    }
+}
+
+impl NotOp for TernaryTruth {
+    fn not(self) -> Self {
+        self.not()
+    }
+}
+
 impl ops::Not for TernaryTruth {
     type Output = Self;
 
@@ -457,12 +1678,93 @@ impl ops::Not for TernaryTruth {
     }
 }
 
+/// `a >> b` is the logical implication `a.implies(b)` (Kleene
+/// implication), **not** a bit shift.
+impl ops::Shr for TernaryTruth {
+    type Output = Self;
+
+    fn shr(self, other: Self) -> Self {
+        self.implies(other)
+    }
+}
+
 impl LogicOperand for TernaryTruth {}
 
+// Packed TernaryTruth Free Functions
+// -----------------------------------------------------------------------------
+
+const PACKED_FALSE_BITS: u8 = 0b0101_0101;
+const PACKED_TRUE_BITS: u8 = 0b1010_1010;
+
+/// Computes the Kleene AND of two packed [`TernaryTruth`] buffers, a whole byte
+/// (4 values) at a time.
+///
+/// The packed layout stores each value in a 2-bit lane, with the value at index
+/// `i` living in byte `i / 4`, at bits `2 * (i % 4)` and `2 * (i % 4) + 1`.
+/// Within a lane, the low bit means "could be false" and the high bit means
+/// "could be true":
+/// - `False` is `0b01`
+/// - `True` is `0b10`
+/// - `Unknown` is `0b11`
+/// - `0b00` is invalid (used as padding in the trailing byte)
+///
+/// With this layout, the result could be false if any of the operands could be
+/// false, and it could be true only if both operands could be true.
+///
+/// Panics if the three slices do not have the same length.
+pub fn ternary_and_packed(a: &[u8], b: &[u8], out: &mut [u8]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+
+    for ((x, y), o) in a.iter().zip(b).zip(out.iter_mut()) {
+        *o = ((x | y) & PACKED_FALSE_BITS) | ((x & y) & PACKED_TRUE_BITS);
+    }
+}
+
+/// Computes the Kleene OR of two packed [`TernaryTruth`] buffers, a whole byte
+/// (4 values) at a time. See [`ternary_and_packed`] for the bit layout.
+///
+/// Panics if the three slices do not have the same length.
+pub fn ternary_or_packed(a: &[u8], b: &[u8], out: &mut [u8]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+
+    for ((x, y), o) in a.iter().zip(b).zip(out.iter_mut()) {
+        *o = ((x & y) & PACKED_FALSE_BITS) | ((x | y) & PACKED_TRUE_BITS);
+    }
+}
+
 // TruthValuesPowerSet Impls
 // -----------------------------------------------------------------------------
 
 impl TruthValuesPowerSet {
+    /// Every set, in declaration order (which is also the order of their
+    /// bitsets, see [`TruthValuesPowerSet::to_bits`]).
+    pub const ALL: [TruthValuesPowerSet; 16] = [
+        TruthValuesPowerSet::____,
+        TruthValuesPowerSet::N___,
+        TruthValuesPowerSet::_F__,
+        TruthValuesPowerSet::NF__,
+        TruthValuesPowerSet::__T_,
+        TruthValuesPowerSet::N_T_,
+        TruthValuesPowerSet::_FT_,
+        TruthValuesPowerSet::NFT_,
+        TruthValuesPowerSet::___B,
+        TruthValuesPowerSet::N__B,
+        TruthValuesPowerSet::_F_B,
+        TruthValuesPowerSet::NF_B,
+        TruthValuesPowerSet::__TB,
+        TruthValuesPowerSet::N_TB,
+        TruthValuesPowerSet::_FTB,
+        TruthValuesPowerSet::NFTB,
+    ];
+
+    /// Builds the set from its membership flags, e.g.
+    /// `new(false, true, true, false) == _FT_`.
+    pub fn new(neither: bool, f: bool, t: bool, both: bool) -> Self {
+        powerset_from_mask(neither as u8 | (f as u8) << 1 | (t as u8) << 2 | (both as u8) << 3)
+    }
+
     pub fn could_be_neither(self) -> bool {
         match self {
             TruthValuesPowerSet::NFTB
@@ -531,7 +1833,170 @@ impl TruthValuesPowerSet {
     }
 
     pub fn is_empty(self) -> bool {
-        return self == TruthValuesPowerSet::____;
+        self == TruthValuesPowerSet::____
+    }
+
+    /// Whether the set still admits at least one truth value (the opposite of
+    /// [`TruthValuesPowerSet::is_empty`]).
+    ///
+    /// Inconsistency only arises when narrowing: intersecting with, or
+    /// refining by (see [`Unknown::refine`]), an observation that shares no
+    /// member with the current set. The logic operations never make a set
+    /// inconsistent, since applying them to non-empty sets always has at least
+    /// one outcome. That's why [`EBelnapian`] has no empty value.
+    pub fn is_consistent(self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns the number of members of the set.
+    pub fn len(self) -> usize {
+        (self as u8).count_ones() as usize
+    }
+
+    /// Returns the set with `value` added to its members.
+    pub fn insert(self, value: Belnapian) -> Self {
+        powerset_from_mask(self as u8 | belnapian_mask(value))
+    }
+
+    /// Same as [`TruthValuesPowerSet::len`], as a `u8` in `0..=4`.
+    pub fn cardinality(self) -> u8 {
+        self.len() as u8
+    }
+
+    pub fn contains(self, value: Belnapian) -> bool {
+        self as u8 & belnapian_mask(value) != 0
+    }
+
+    /// Returns an iterator over the members of the set.
+    pub fn members(self) -> MembersIter {
+        MembersIter {
+            bits: self as u8,
+            cursor: 0,
+        }
+    }
+
+    /// Belnap's conjunction, lifted over the members of both sets. The result
+    /// is `____` if any of the sets is empty.
+    pub fn and(self, other: Self) -> Self {
+        lift_powerset_op(Belnapian::and, self, other)
+    }
+
+    /// Belnap's disjunction, lifted over the members of both sets. The result
+    /// is `____` if any of the sets is empty.
+    pub fn or(self, other: Self) -> Self {
+        lift_powerset_op(Belnapian::or, self, other)
+    }
+
+    /// Belnap's negation, applied to every member of the set.
+    pub fn not(self) -> Self {
+        self.members().map(Belnapian::not).collect()
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        powerset_from_mask(self as u8 | other as u8)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        powerset_from_mask(self as u8 & other as u8)
+    }
+
+    /// Returns the members of `self` that are not members of `other`.
+    pub fn difference(self, other: Self) -> Self {
+        powerset_from_mask(self as u8 & !(other as u8))
+    }
+
+    /// Returns the complement of the set, relative to `NFTB`.
+    pub fn complement(self) -> Self {
+        powerset_from_mask(!(self as u8))
+    }
+
+    /// Returns the truth values admitted by exactly one of both sets, that is,
+    /// the points where they disagree (`____` if the sets are identical).
+    pub fn symmetric_difference(self, other: Self) -> Self {
+        powerset_from_mask(self as u8 ^ other as u8)
+    }
+
+    /// Whether every member of `self` is also a member of `other`.
+    pub fn is_subset_of(self, other: Self) -> bool {
+        self as u8 & !(other as u8) == 0
+    }
+
+    /// Whether every member of `other` is also a member of `self`.
+    pub fn is_superset_of(self, other: Self) -> bool {
+        other.is_subset_of(self)
+    }
+
+    /// Returns the set as a 4-bit mask (`0..=15`), where bit 0 = Neither,
+    /// bit 1 = False, bit 2 = True, and bit 3 = Both. The set operations are
+    /// plain bitwise operations on this representation.
+    pub fn to_bits(self) -> u8 {
+        self as u8
+    }
+
+    /// Inverse of [`TruthValuesPowerSet::to_bits`]. Returns `None` if any bit
+    /// above bit 3 is set (see [`TruthValuesPowerSet::from_bitset_checked`]
+    /// to get a [`ConversionError`] instead).
+    pub fn from_bits(bits: u8) -> Option<Self> {
+        match bits {
+            0b0000..=0b1111 => Some(powerset_from_mask(bits)),
+            _ => None,
+        }
+    }
+
+    /// Like [`TruthValuesPowerSet::from_bits`], but fails with
+    /// [`ConversionError::OutOfRange`] if any bit above bit 3 is set.
+    pub fn from_bitset_checked(bits: u8) -> Result<Self, ConversionError> {
+        Self::from_bits(bits).ok_or(ConversionError::OutOfRange)
+    }
+
+    /// Checks that the bitset of the set is in range and agrees with the
+    /// `could_be_*` membership flags. This always holds for the enum itself,
+    /// it is meant as a safety net for values built from raw bitsets.
+    pub fn validate(self) -> bool {
+        let bits = self.to_bits();
+        bits <= 0b1111
+            && (bits & 0b0001 != 0) == self.could_be_neither()
+            && (bits & 0b0010 != 0) == self.could_be_false()
+            && (bits & 0b0100 != 0) == self.could_be_true()
+            && (bits & 0b1000 != 0) == self.could_be_both()
+    }
+
+    /// Returns the membership flags as `[neither, false, true, both]`.
+    pub fn debug_bits(self) -> [bool; 4] {
+        let mask = self as u8;
+        [
+            mask & 0b0001 != 0,
+            mask & 0b0010 != 0,
+            mask & 0b0100 != 0,
+            mask & 0b1000 != 0,
+        ]
+    }
+
+    /// Returns the membership flags formatted like `"N=0 F=1 T=1 B=0"`.
+    pub fn debug_str(self) -> &'static str {
+        MASK_DEBUG_STRS[self as usize]
+    }
+
+    /// Returns the most specific typed representation of the set.
+    pub fn canonical(self) -> CanonicalValue {
+        match self {
+            TruthValuesPowerSet::____ => CanonicalValue::Empty,
+            TruthValuesPowerSet::N___ => CanonicalValue::Known(Belnapian::Neither),
+            TruthValuesPowerSet::_F__ => CanonicalValue::Known(Belnapian::False),
+            TruthValuesPowerSet::NF__ => CanonicalValue::Unknown(Unknown::NF__),
+            TruthValuesPowerSet::__T_ => CanonicalValue::Known(Belnapian::True),
+            TruthValuesPowerSet::N_T_ => CanonicalValue::Unknown(Unknown::N_T_),
+            TruthValuesPowerSet::_FT_ => CanonicalValue::Unknown(Unknown::_FT_),
+            TruthValuesPowerSet::NFT_ => CanonicalValue::Unknown(Unknown::NFT_),
+            TruthValuesPowerSet::___B => CanonicalValue::Known(Belnapian::Both),
+            TruthValuesPowerSet::N__B => CanonicalValue::Unknown(Unknown::N__B),
+            TruthValuesPowerSet::_F_B => CanonicalValue::Unknown(Unknown::_F_B),
+            TruthValuesPowerSet::NF_B => CanonicalValue::Unknown(Unknown::NF_B),
+            TruthValuesPowerSet::__TB => CanonicalValue::Unknown(Unknown::__TB),
+            TruthValuesPowerSet::N_TB => CanonicalValue::Unknown(Unknown::N_TB),
+            TruthValuesPowerSet::_FTB => CanonicalValue::Unknown(Unknown::_FTB),
+            TruthValuesPowerSet::NFTB => CanonicalValue::Unknown(Unknown::NFTB),
+        }
     }
 }
 
@@ -561,10 +2026,111 @@ impl TruthValuesSet for TruthValuesPowerSet {
     }
 }
 
+impl FromIterator<Belnapian> for TruthValuesPowerSet {
+    fn from_iter<I: IntoIterator<Item = Belnapian>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(TruthValuesPowerSet::____, TruthValuesPowerSet::insert)
+    }
+}
+
+/// Sets are ordered by inclusion, which is a partial order: `NF__` and `__TB`
+/// are incomparable, so there is no `Ord` impl.
+///
+/// ```
+/// use belnapian::TruthValuesPowerSet;
+///
+/// assert!(TruthValuesPowerSet::_F__ < TruthValuesPowerSet::_FT_);
+/// assert_eq!(
+///     None,
+///     TruthValuesPowerSet::NF__.partial_cmp(&TruthValuesPowerSet::__TB)
+/// );
+/// ```
+impl PartialOrd for TruthValuesPowerSet {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match (self.is_subset_of(*other), self.is_superset_of(*other)) {
+            (true, true) => Some(core::cmp::Ordering::Equal),
+            (true, false) => Some(core::cmp::Ordering::Less),
+            (false, true) => Some(core::cmp::Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+// MembersIter Impls
+// -----------------------------------------------------------------------------
+
+impl Iterator for MembersIter {
+    type Item = Belnapian;
+
+    fn next(&mut self) -> Option<Belnapian> {
+        while self.cursor < 4 {
+            let cursor = self.cursor;
+            self.cursor += 1;
+            if self.bits & (1 << cursor) != 0 {
+                return match cursor {
+                    0 => Some(Belnapian::Neither),
+                    1 => Some(Belnapian::False),
+                    2 => Some(Belnapian::True),
+                    _ => Some(Belnapian::Both),
+                };
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.bits >> self.cursor).count_ones() as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for MembersIter {}
+
+// LogicScan Impls
+// -----------------------------------------------------------------------------
+
+impl<I: Iterator> Iterator for LogicScan<I>
+where
+    I::Item: LogicOperand,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let value = self.iter.next()?;
+        let acc = match self.acc {
+            Some(acc) => (self.op)(acc, value),
+            None => value,
+        };
+        self.acc = Some(acc);
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for LogicScan<I> where I::Item: LogicOperand {}
+
 // Unknown Impls
 // -----------------------------------------------------------------------------
 
 impl Unknown {
+    /// Every value, in declaration order.
+    pub const ALL: [Unknown; 11] = [
+        Unknown::NF__,
+        Unknown::N_T_,
+        Unknown::_FT_,
+        Unknown::NFT_,
+        Unknown::N__B,
+        Unknown::_F_B,
+        Unknown::NF_B,
+        Unknown::__TB,
+        Unknown::N_TB,
+        Unknown::_FTB,
+        Unknown::NFTB,
+    ];
+
     pub fn and(self, other: Self) -> EBelnapian {
         match (self, other) {
             (Unknown::NF__, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
@@ -752,66 +2318,205 @@ impl Unknown {
             (Unknown::_F_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
             (Unknown::_F_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_F_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::_F_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_F_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_F_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::_F_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_F_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_F_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_F_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::NF_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::N_T_) => EBelnapian::Known(Belnapian::True),
+            (Unknown::__TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::N_TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::N_TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::N_TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::_FTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_FTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NFTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::NFTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFTB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+        }
+    }
+
+    /// Computes the same results as [`Unknown::and`], by lifting the 4×4
+    /// Belnapian table over the bitmasks of both operands instead of looking
+    /// the pair up in the 11×11 table.
+    pub fn and_masked(self, other: Self) -> EBelnapian {
+        lift_unknown_mask_op(&AND_MASKS, self, other)
+    }
+
+    /// Computes the same results as [`Unknown::or`], see
+    /// [`Unknown::and_masked`].
+    pub fn or_masked(self, other: Self) -> EBelnapian {
+        lift_unknown_mask_op(&OR_MASKS, self, other)
+    }
+
+    pub fn xor(self, other: Self) -> EBelnapian {
+        match (self, other) {
+            (Unknown::NF__, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
+            (Unknown::NF__, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NF__, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NF__, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF__, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF__, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF__, Unknown::__TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF__, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF__, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF__, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::N_T_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NF__),
+            (Unknown::N_T_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::N_T_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::N_T_, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_T_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::__TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_T_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_T_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::_FT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::_FT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
+            (Unknown::_FT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::_FT_, Unknown::N__B) => EBelnapian::Unknown(Unknown::N__B),
+            (Unknown::_FT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FT_, Unknown::__TB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FT_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NFT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::__TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N__B, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N__B),
+            (Unknown::N__B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::__TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N__B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::_F_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::_F_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_F_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_F_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_F_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::_F_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
-            (Unknown::_F_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_F_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::_F_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::_F_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_F_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::_F_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_F_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NF_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NF_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NF_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::NF_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NF_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NF_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::NF_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::NF_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::__TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::N_T_) => EBelnapian::Known(Belnapian::True),
-            (Unknown::__TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::__TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::__TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::__TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::N_TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
-            (Unknown::N_TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::N_TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::__TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::__TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::__TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::__TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NF_B),
+            (Unknown::N_TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::_FTB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::_FTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::_FTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::_FTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::_FTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::_FTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::_FTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::_FTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::_FTB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FTB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NFTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NFTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NFTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::NFTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NFTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::NFTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
         }
@@ -833,12 +2538,48 @@ impl Unknown {
         }
     }
 
+    /// Belnap's conflation (see [`Belnapian::conflate`]), applied to every
+    /// member of the set.
+    pub fn conflate(self) -> Self {
+        match self {
+            Unknown::NF__ => Unknown::_F_B,
+            Unknown::N_T_ => Unknown::__TB,
+            Unknown::_FT_ => Unknown::_FT_,
+            Unknown::NFT_ => Unknown::_FTB,
+            Unknown::N__B => Unknown::N__B,
+            Unknown::_F_B => Unknown::NF__,
+            Unknown::NF_B => Unknown::NF_B,
+            Unknown::__TB => Unknown::N_T_,
+            Unknown::N_TB => Unknown::N_TB,
+            Unknown::_FTB => Unknown::NFT_,
+            Unknown::NFTB => Unknown::NFTB,
+        }
+    }
+
+    /// Narrows the value with a new observation, keeping only the truth values
+    /// admitted by both. The result may be a smaller unknown, a single known
+    /// value, or `____` if the observation contradicts the current value.
+    pub fn refine(self, observation: Unknown) -> TruthValuesPowerSet {
+        TruthValuesPowerSet::from(self).intersection(observation.into())
+    }
+
+    /// Combines two estimates that could both hold, admitting every truth value
+    /// admitted by either (the union of both sets). The union of two sets with
+    /// at least two members each has at least two members too, so the result
+    /// is always an `Unknown`.
+    pub fn widen(self, other: Unknown) -> Unknown {
+        match Unknown::try_from(TruthValuesPowerSet::from(self).union(other.into())) {
+            Ok(value) => value,
+            Err(_) => unreachable!(), // Both operands have at least two members
+        }
+    }
+
     pub fn superposition(self, other: Self) -> EBelnapian {
         match (self, other) {
             (Unknown::NF__, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
-            (Unknown::NF__, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFT_),
-            (Unknown::NF__, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
-            (Unknown::NF__, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NF__, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NF__, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::NF__, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF__, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::NF__, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::NF__, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
@@ -846,34 +2587,34 @@ impl Unknown {
             (Unknown::NF__, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF__, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::NF__, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::N_T_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::N_T_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::N_T_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
-            (Unknown::N_T_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
-            (Unknown::N_T_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::N_T_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::N_T_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::N_T_, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_T_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::N_T_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::N_T_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::N_T_, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::N_T_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
             (Unknown::N_T_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::N_T_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::_FT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::_FT_),
-            (Unknown::_FT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_FT_),
-            (Unknown::_FT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
-            (Unknown::_FT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_FT_),
+            (Unknown::_FT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FT_, Unknown::N__B) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::_FT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::_FT_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FT_, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::_FT_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FT_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FT_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::NFT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFT_),
-            (Unknown::NFT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFT_),
-            (Unknown::NFT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
-            (Unknown::NFT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::NFT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFT_, Unknown::N__B) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NFT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::NFT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::NFT_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFT_, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::NFT_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
@@ -891,16 +2632,16 @@ impl Unknown {
             (Unknown::N__B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::N__B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::_F_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::_F_B),
-            (Unknown::_F_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::_F_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::_F_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_F_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::_F_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::_F_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::_F_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::_F_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::_F_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::_F_B),
-            (Unknown::_F_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::_F_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::_F_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::_F_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_F_B, Unknown::__TB) => EBelnapian::Known(Belnapian::Both),
+            (Unknown::_F_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::_F_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::_F_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::NF_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF_B),
             (Unknown::NF_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NF_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
@@ -917,7 +2658,7 @@ impl Unknown {
             (Unknown::__TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::__TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::__TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::__TB),
-            (Unknown::__TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::_F_B) => EBelnapian::Known(Belnapian::Both),
             (Unknown::__TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::__TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::__TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::__TB),
@@ -928,7 +2669,7 @@ impl Unknown {
             (Unknown::N_TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::N_TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::N_TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
-            (Unknown::N_TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::N_TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::N_TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::N_TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::N_TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
@@ -939,7 +2680,7 @@ impl Unknown {
             (Unknown::_FTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::_FTB),
-            (Unknown::_FTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::_FTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::_FTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::_FTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_FTB),
@@ -950,7 +2691,7 @@ impl Unknown {
             (Unknown::NFTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
             (Unknown::NFTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::NFTB),
-            (Unknown::NFTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::NFTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
             (Unknown::NFTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
             (Unknown::NFTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
             (Unknown::NFTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NFTB),
@@ -1085,6 +2826,153 @@ impl Unknown {
         }
     }
 
+    /// Material implication, `¬a ∨ b`, lifted over every pair of possible
+    /// values of the operands.
+    pub fn implies(self, other: Self) -> EBelnapian {
+        match (self, other) {
+            (Unknown::NF__, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::NFT_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::_F_B) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::__TB) => EBelnapian::Known(Belnapian::True),
+            (Unknown::NF__, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::_FTB) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF__, Unknown::NFTB) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::N_T_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
+            (Unknown::N_T_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::N_T_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::N_T_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::N_T_, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_T_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::N_T_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_T_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_T_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::_FT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::_FT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
+            (Unknown::_FT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::_FT_, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FT_, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_FT_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FT_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FT_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NFT_, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+            (Unknown::NFT_, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFT_, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::NFT_, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFT_, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFT_, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N__B, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::N__B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::N__B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N__B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_F_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::N_T_) => EBelnapian::Known(Belnapian::True),
+            (Unknown::_F_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_F_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::NF_B, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NF_B, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::NFT_) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::_F_B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::NF_B, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::_FTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NF_B, Unknown::NFTB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::__TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::__TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::__TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::__TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
+            (Unknown::__TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::__TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::__TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::__TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::__TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::N_TB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::N_TB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::N_TB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::N_TB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::_FTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::_FTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::_FTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::_FTB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+            (Unknown::_FTB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NF__) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+            (Unknown::NFTB, Unknown::_FT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFTB, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+            (Unknown::NFTB, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+            (Unknown::NFTB, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NFTB),
+            (Unknown::NFTB, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+        }
+    }
+
+    /// The knowledge meet of Belnap's bilattice (see
+    /// [`Belnapian::knowledge_meet`]), lifted pointwise over the members of
+    /// both sets. The same as [`Unknown::annihilation`].
+    ///
+    /// It is commutative, but unlike its `Belnapian` counterpart it is not
+    /// idempotent: `_FT_.knowledge_meet(_FT_)` also admits `False ⊗ True`, so it
+    /// results in `NFT_`.
+    pub fn knowledge_meet(self, other: Self) -> EBelnapian {
+        self.annihilation(other)
+    }
+
+    /// The knowledge join of Belnap's bilattice (see
+    /// [`Belnapian::knowledge_join`]), lifted pointwise over the members of
+    /// both sets. The same as [`Unknown::superposition`]. See
+    /// [`Unknown::knowledge_meet`] about idempotence.
+    pub fn knowledge_join(self, other: Self) -> EBelnapian {
+        self.superposition(other)
+    }
+
     pub fn eq(self, other: Self) -> EBelnapian {
         match (self, other) {
             // DO NOT REORDER THESE MATCHES
@@ -1167,6 +3055,22 @@ impl Unknown {
     pub fn is_empty(self) -> bool {
         false
     }
+
+    /// See [`TruthValuesPowerSet::debug_bits`].
+    pub fn debug_bits(self) -> [bool; 4] {
+        TruthValuesPowerSet::from(self).debug_bits()
+    }
+
+    /// See [`TruthValuesPowerSet::debug_str`].
+    pub fn debug_str(self) -> &'static str {
+        TruthValuesPowerSet::from(self).debug_str()
+    }
+}
+
+impl NotOp for Unknown {
+    fn not(self) -> Self {
+        self.not()
+    }
 }
 
 impl ops::Not for Unknown {
@@ -1177,6 +3081,8 @@ impl ops::Not for Unknown {
     }
 }
 
+impl TruthValue for Unknown {}
+
 impl TruthValuesSet for Unknown {
     fn could_be_neither(self) -> bool {
         self.could_be_neither()
@@ -1198,11 +3104,157 @@ impl TruthValuesSet for Unknown {
         true
     }
 
-    fn is_empty(self) -> bool {
-        false
+    fn is_empty(self) -> bool {
+        false
+    }
+}
+
+// Possibility Sets Free Functions
+// -----------------------------------------------------------------------------
+// The discriminant of every `TruthValuesPowerSet` variant is a 4-bit mask, where
+// bit 0 = Neither, bit 1 = False, bit 2 = True, and bit 3 = Both.
+
+const MASK_DEBUG_STRS: [&str; 16] = [
+    "N=0 F=0 T=0 B=0",
+    "N=1 F=0 T=0 B=0",
+    "N=0 F=1 T=0 B=0",
+    "N=1 F=1 T=0 B=0",
+    "N=0 F=0 T=1 B=0",
+    "N=1 F=0 T=1 B=0",
+    "N=0 F=1 T=1 B=0",
+    "N=1 F=1 T=1 B=0",
+    "N=0 F=0 T=0 B=1",
+    "N=1 F=0 T=0 B=1",
+    "N=0 F=1 T=0 B=1",
+    "N=1 F=1 T=0 B=1",
+    "N=0 F=0 T=1 B=1",
+    "N=1 F=0 T=1 B=1",
+    "N=0 F=1 T=1 B=1",
+    "N=1 F=1 T=1 B=1",
+];
+
+fn powerset_from_mask(mask: u8) -> TruthValuesPowerSet {
+    match mask & 0b1111 {
+        0b0000 => TruthValuesPowerSet::____,
+        0b0001 => TruthValuesPowerSet::N___,
+        0b0010 => TruthValuesPowerSet::_F__,
+        0b0011 => TruthValuesPowerSet::NF__,
+        0b0100 => TruthValuesPowerSet::__T_,
+        0b0101 => TruthValuesPowerSet::N_T_,
+        0b0110 => TruthValuesPowerSet::_FT_,
+        0b0111 => TruthValuesPowerSet::NFT_,
+        0b1000 => TruthValuesPowerSet::___B,
+        0b1001 => TruthValuesPowerSet::N__B,
+        0b1010 => TruthValuesPowerSet::_F_B,
+        0b1011 => TruthValuesPowerSet::NF_B,
+        0b1100 => TruthValuesPowerSet::__TB,
+        0b1101 => TruthValuesPowerSet::N_TB,
+        0b1110 => TruthValuesPowerSet::_FTB,
+        0b1111 => TruthValuesPowerSet::NFTB,
+        _ => unreachable!(),
+    }
+}
+
+fn belnapian_mask(value: Belnapian) -> u8 {
+    1 << (value as u8)
+}
+
+// Applies `op` to every pair of members of both sets, and returns the set of
+// outcomes (empty if any of the sets is empty).
+fn lift_powerset_op(
+    op: impl Fn(Belnapian, Belnapian) -> Belnapian,
+    a: TruthValuesPowerSet,
+    b: TruthValuesPowerSet,
+) -> TruthValuesPowerSet {
+    let mut mask = 0;
+    for x in a.members() {
+        for y in b.members() {
+            mask |= belnapian_mask(op(x, y));
+        }
+    }
+    powerset_from_mask(mask)
+}
+
+// The outcome masks of a connective, indexed by the discriminants of both
+// operands: `table[a as usize][b as usize] == belnapian_mask(op.apply(a, b))`.
+const fn connective_mask_table(op: Connective) -> [[u8; 4]; 4] {
+    let mut table = [[0; 4]; 4];
+    let mut i = 0;
+    while i < 4 {
+        let mut j = 0;
+        while j < 4 {
+            table[i][j] = 1 << (op.apply(Belnapian::ALL[i], Belnapian::ALL[j]) as u8);
+            j += 1;
+        }
+        i += 1;
+    }
+    table
+}
+
+const AND_MASKS: [[u8; 4]; 4] = connective_mask_table(Connective::And);
+const OR_MASKS: [[u8; 4]; 4] = connective_mask_table(Connective::Or);
+
+// Same as `lift_powerset_op`, but working directly on the 4-bit masks with a
+// precomputed outcome table.
+fn lift_mask_op(table: &[[u8; 4]; 4], a: u8, b: u8) -> u8 {
+    let mut mask = 0;
+    for (i, row) in table.iter().enumerate() {
+        if a & (1 << i) != 0 {
+            for (j, outcomes) in row.iter().enumerate() {
+                if b & (1 << j) != 0 {
+                    mask |= outcomes;
+                }
+            }
+        }
+    }
+    mask
+}
+
+fn lift_unknown_mask_op(table: &[[u8; 4]; 4], a: Unknown, b: Unknown) -> EBelnapian {
+    let a = TruthValuesPowerSet::from(a).to_bits();
+    let b = TruthValuesPowerSet::from(b).to_bits();
+
+    match powerset_to_ebelnapian(powerset_from_mask(lift_mask_op(table, a, b))) {
+        Some(value) => value,
+        None => unreachable!(), // Both operands have at least two possible values
+    }
+}
+
+fn lift_binary_op(
+    op: impl Fn(Belnapian, Belnapian) -> Belnapian,
+    a: EBelnapian,
+    b: EBelnapian,
+) -> EBelnapian {
+    let set = lift_powerset_op(op, ebelnapian_to_powerset(a), ebelnapian_to_powerset(b));
+
+    match powerset_to_ebelnapian(set) {
+        Some(value) => value,
+        None => unreachable!(), // Both operands have at least one possible value
     }
 }
 
+/// Lifts a binary [`Belnapian`] operation to [`EBelnapian`] values.
+///
+/// The lifted operation applies `op` to every pair of possible values of its
+/// operands, and returns the set of outcomes (collapsed into `Known` when it
+/// is a singleton). Since an `EBelnapian` never represents the empty set, the
+/// outcome is never empty either. As with the built-in operations, both
+/// operands are treated as independent, so e.g. `x ∧ x` for an unknown `x` can
+/// admit more values than `x` itself.
+pub fn lift_binary(
+    op: impl Fn(Belnapian, Belnapian) -> Belnapian,
+) -> impl Fn(EBelnapian, EBelnapian) -> EBelnapian {
+    move |a, b| lift_binary_op(&op, a, b)
+}
+
+fn ebelnapian_to_powerset(value: EBelnapian) -> TruthValuesPowerSet {
+    value.into()
+}
+
+fn powerset_to_ebelnapian(set: TruthValuesPowerSet) -> Option<EBelnapian> {
+    set.try_into().ok()
+}
+
 // EBelnapian Free Functions
 // -----------------------------------------------------------------------------
 
@@ -1304,6 +3356,154 @@ fn or_ebelnapian_unknown(a: Belnapian, b: Unknown) -> EBelnapian {
     }
 }
 
+fn xor_ebelnapian_unknown(a: Belnapian, b: Unknown) -> EBelnapian {
+    match (a, b) {
+        (Belnapian::Neither, Unknown::NF__) => EBelnapian::Known(Belnapian::Neither),
+        (Belnapian::Neither, Unknown::N_T_) => EBelnapian::Known(Belnapian::Neither),
+        (Belnapian::Neither, Unknown::_FT_) => EBelnapian::Known(Belnapian::Neither),
+        (Belnapian::Neither, Unknown::NFT_) => EBelnapian::Known(Belnapian::Neither),
+        (Belnapian::Neither, Unknown::N__B) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::Neither, Unknown::_F_B) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::Neither, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::Neither, Unknown::__TB) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::Neither, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::Neither, Unknown::_FTB) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::Neither, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::False, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::False, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::False, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
+        (Belnapian::False, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+        (Belnapian::False, Unknown::N__B) => EBelnapian::Unknown(Unknown::N__B),
+        (Belnapian::False, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::False, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
+        (Belnapian::False, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::False, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+        (Belnapian::False, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+        (Belnapian::False, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+        (Belnapian::True, Unknown::NF__) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::True, Unknown::N_T_) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::True, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
+        (Belnapian::True, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+        (Belnapian::True, Unknown::N__B) => EBelnapian::Unknown(Unknown::N__B),
+        (Belnapian::True, Unknown::_F_B) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::True, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_TB),
+        (Belnapian::True, Unknown::__TB) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::True, Unknown::N_TB) => EBelnapian::Unknown(Unknown::NF_B),
+        (Belnapian::True, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+        (Belnapian::True, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+        (Belnapian::Both, Unknown::NF__) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::Both, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::Both, Unknown::_FT_) => EBelnapian::Known(Belnapian::Both),
+        (Belnapian::Both, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::Both, Unknown::N__B) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::Both, Unknown::_F_B) => EBelnapian::Known(Belnapian::Both),
+        (Belnapian::Both, Unknown::NF_B) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::Both, Unknown::__TB) => EBelnapian::Known(Belnapian::Both),
+        (Belnapian::Both, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::Both, Unknown::_FTB) => EBelnapian::Known(Belnapian::Both),
+        (Belnapian::Both, Unknown::NFTB) => EBelnapian::Unknown(Unknown::_F_B),
+    }
+}
+
+fn implies_ebelnapian_unknown(a: Belnapian, b: Unknown) -> EBelnapian {
+    match (a, b) {
+        (Belnapian::Neither, Unknown::NF__) => EBelnapian::Known(Belnapian::Neither),
+        (Belnapian::Neither, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::_FT_) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::NFT_) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::N__B) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::_F_B) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::NF_B) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::__TB) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::Neither, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::_FTB) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::Neither, Unknown::NFTB) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::False, Unknown::NF__) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::N_T_) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::_FT_) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::NFT_) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::N__B) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::_F_B) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::NF_B) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::__TB) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::N_TB) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::_FTB) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::NFTB) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::True, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
+        (Belnapian::True, Unknown::N_T_) => EBelnapian::Unknown(Unknown::N_T_),
+        (Belnapian::True, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
+        (Belnapian::True, Unknown::NFT_) => EBelnapian::Unknown(Unknown::NFT_),
+        (Belnapian::True, Unknown::N__B) => EBelnapian::Unknown(Unknown::N__B),
+        (Belnapian::True, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::True, Unknown::NF_B) => EBelnapian::Unknown(Unknown::NF_B),
+        (Belnapian::True, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::True, Unknown::N_TB) => EBelnapian::Unknown(Unknown::N_TB),
+        (Belnapian::True, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
+        (Belnapian::True, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
+        (Belnapian::Both, Unknown::NF__) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::N_T_) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::Both, Unknown::_FT_) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::NFT_) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::N__B) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::_F_B) => EBelnapian::Known(Belnapian::Both),
+        (Belnapian::Both, Unknown::NF_B) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::N_TB) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::_FTB) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::Both, Unknown::NFTB) => EBelnapian::Unknown(Unknown::__TB),
+    }
+}
+
+// Implication is not commutative, so the unknown/known case needs its own table.
+fn implies_unknown_ebelnapian(a: Unknown, b: Belnapian) -> EBelnapian {
+    match (a, b) {
+        (Unknown::NF__, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::NF__, Belnapian::False) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::NF__, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::NF__, Belnapian::Both) => EBelnapian::Known(Belnapian::True),
+        (Unknown::N_T_, Belnapian::Neither) => EBelnapian::Known(Belnapian::Neither),
+        (Unknown::N_T_, Belnapian::False) => EBelnapian::Unknown(Unknown::NF__),
+        (Unknown::N_T_, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::N_T_, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::_FT_, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::_FT_, Belnapian::False) => EBelnapian::Unknown(Unknown::_FT_),
+        (Unknown::_FT_, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::_FT_, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::NFT_, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::NFT_, Belnapian::False) => EBelnapian::Unknown(Unknown::NFT_),
+        (Unknown::NFT_, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::NFT_, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::N__B, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::N__B, Belnapian::False) => EBelnapian::Unknown(Unknown::N__B),
+        (Unknown::N__B, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::N__B, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::_F_B, Belnapian::Neither) => EBelnapian::Known(Belnapian::True),
+        (Unknown::_F_B, Belnapian::False) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::_F_B, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::_F_B, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::NF_B, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::NF_B, Belnapian::False) => EBelnapian::Unknown(Unknown::N_TB),
+        (Unknown::NF_B, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::NF_B, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::__TB, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::__TB, Belnapian::False) => EBelnapian::Unknown(Unknown::_F_B),
+        (Unknown::__TB, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::__TB, Belnapian::Both) => EBelnapian::Known(Belnapian::Both),
+        (Unknown::N_TB, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::N_TB, Belnapian::False) => EBelnapian::Unknown(Unknown::NF_B),
+        (Unknown::N_TB, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::N_TB, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::_FTB, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::_FTB, Belnapian::False) => EBelnapian::Unknown(Unknown::_FTB),
+        (Unknown::_FTB, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::_FTB, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+        (Unknown::NFTB, Belnapian::Neither) => EBelnapian::Unknown(Unknown::N_T_),
+        (Unknown::NFTB, Belnapian::False) => EBelnapian::Unknown(Unknown::NFTB),
+        (Unknown::NFTB, Belnapian::True) => EBelnapian::Known(Belnapian::True),
+        (Unknown::NFTB, Belnapian::Both) => EBelnapian::Unknown(Unknown::__TB),
+    }
+}
+
 fn superposition_ebelnapian_unknown(a: Belnapian, b: Unknown) -> EBelnapian {
     match (a, b) {
         (Belnapian::Neither, Unknown::NF__) => EBelnapian::Unknown(Unknown::NF__),
@@ -1318,22 +3518,22 @@ fn superposition_ebelnapian_unknown(a: Belnapian, b: Unknown) -> EBelnapian {
         (Belnapian::Neither, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
         (Belnapian::Neither, Unknown::NFTB) => EBelnapian::Unknown(Unknown::NFTB),
         (Belnapian::False, Unknown::NF__) => EBelnapian::Known(Belnapian::False),
-        (Belnapian::False, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_FT_),
-        (Belnapian::False, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_FT_),
-        (Belnapian::False, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_FT_),
+        (Belnapian::False, Unknown::N_T_) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::False, Unknown::_FT_) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::False, Unknown::NFT_) => EBelnapian::Unknown(Unknown::_F_B),
         (Belnapian::False, Unknown::N__B) => EBelnapian::Unknown(Unknown::_F_B),
         (Belnapian::False, Unknown::_F_B) => EBelnapian::Unknown(Unknown::_F_B),
         (Belnapian::False, Unknown::NF_B) => EBelnapian::Unknown(Unknown::_F_B),
-        (Belnapian::False, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
-        (Belnapian::False, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_FTB),
-        (Belnapian::False, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_FTB),
-        (Belnapian::False, Unknown::NFTB) => EBelnapian::Unknown(Unknown::_FTB),
-        (Belnapian::True, Unknown::NF__) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::False, Unknown::__TB) => EBelnapian::Known(Belnapian::Both),
+        (Belnapian::False, Unknown::N_TB) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::False, Unknown::_FTB) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::False, Unknown::NFTB) => EBelnapian::Unknown(Unknown::_F_B),
+        (Belnapian::True, Unknown::NF__) => EBelnapian::Unknown(Unknown::__TB),
         (Belnapian::True, Unknown::N_T_) => EBelnapian::Known(Belnapian::True),
-        (Belnapian::True, Unknown::_FT_) => EBelnapian::Known(Belnapian::True),
-        (Belnapian::True, Unknown::NFT_) => EBelnapian::Known(Belnapian::True),
+        (Belnapian::True, Unknown::_FT_) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::True, Unknown::NFT_) => EBelnapian::Unknown(Unknown::__TB),
         (Belnapian::True, Unknown::N__B) => EBelnapian::Unknown(Unknown::__TB),
-        (Belnapian::True, Unknown::_F_B) => EBelnapian::Unknown(Unknown::__TB),
+        (Belnapian::True, Unknown::_F_B) => EBelnapian::Known(Belnapian::Both),
         (Belnapian::True, Unknown::NF_B) => EBelnapian::Unknown(Unknown::__TB),
         (Belnapian::True, Unknown::__TB) => EBelnapian::Unknown(Unknown::__TB),
         (Belnapian::True, Unknown::N_TB) => EBelnapian::Unknown(Unknown::__TB),
@@ -1449,6 +3649,170 @@ impl EBelnapian {
         }
     }
 
+    pub fn is_known(self) -> bool {
+        !self.is_unknown()
+    }
+
+    /// Returns the truth value once it's certain (`Known`), or `None` while
+    /// it's still `Unknown`.
+    pub fn resolve(self) -> Option<Belnapian> {
+        match self {
+            EBelnapian::Known(value) => Some(value),
+            EBelnapian::Unknown(_) => None,
+        }
+    }
+
+    /// The 15 values of the domain: the 4 known values followed by the 11
+    /// unknown ones.
+    pub const ALL: [EBelnapian; 15] = [
+        EBelnapian::Known(Belnapian::Neither),
+        EBelnapian::Known(Belnapian::False),
+        EBelnapian::Known(Belnapian::True),
+        EBelnapian::Known(Belnapian::Both),
+        EBelnapian::Unknown(Unknown::NF__),
+        EBelnapian::Unknown(Unknown::N_T_),
+        EBelnapian::Unknown(Unknown::_FT_),
+        EBelnapian::Unknown(Unknown::NFT_),
+        EBelnapian::Unknown(Unknown::N__B),
+        EBelnapian::Unknown(Unknown::_F_B),
+        EBelnapian::Unknown(Unknown::NF_B),
+        EBelnapian::Unknown(Unknown::__TB),
+        EBelnapian::Unknown(Unknown::N_TB),
+        EBelnapian::Unknown(Unknown::_FTB),
+        EBelnapian::Unknown(Unknown::NFTB),
+    ];
+
+    /// Same as [`EBelnapian::ALL`].
+    pub const fn all() -> [EBelnapian; 15] {
+        Self::ALL
+    }
+
+    /// Groups the values by the result of the `key` function.
+    #[cfg(feature = "alloc")]
+    pub fn partition_by<K: Ord>(
+        values: &[EBelnapian],
+        key: impl Fn(EBelnapian) -> K,
+    ) -> BTreeMap<K, Vec<EBelnapian>> {
+        let mut partition: BTreeMap<K, Vec<EBelnapian>> = BTreeMap::new();
+        for value in values {
+            partition.entry(key(*value)).or_default().push(*value);
+        }
+        partition
+    }
+
+    /// Computes the conjunction of all the values, starting from `Known(True)`.
+    ///
+    /// The iteration stops as soon as the accumulated result becomes
+    /// `Known(False)`, as it's absorbing for AND, whichever inputs produced
+    /// it (e.g. `Known(Neither)` and `Known(Both)` already conjoin to it).
+    pub fn and_all(values: impl IntoIterator<Item = EBelnapian>) -> Self {
+        let mut result = EBelnapian::Known(Belnapian::True);
+        for value in values {
+            result = result.and(value);
+            if result == EBelnapian::Known(Belnapian::False) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Computes the disjunction of all the values, starting from `Known(False)`.
+    ///
+    /// The iteration stops as soon as the accumulated result becomes
+    /// `Known(True)`, as it's absorbing for OR, whichever inputs produced
+    /// it (e.g. `Known(Neither)` and `Known(Both)` already disjoin to it).
+    pub fn or_all(values: impl IntoIterator<Item = EBelnapian>) -> Self {
+        let mut result = EBelnapian::Known(Belnapian::False);
+        for value in values {
+            result = result.or(value);
+            if result == EBelnapian::Known(Belnapian::True) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Returns the uniform probability distribution over the possible truth
+    /// values, indexed as `[Neither, False, True, Both]`. Each possible value
+    /// gets `1 / n` (where `n` is the number of possible values), and every other
+    /// value gets `0`.
+    pub fn uniform_distribution(self) -> [f64; 4] {
+        let mask = ebelnapian_to_powerset(self) as u8;
+        let probability = 1.0 / mask.count_ones() as f64;
+
+        let mut distribution = [0.0; 4];
+        for (i, p) in distribution.iter_mut().enumerate() {
+            if mask & (1 << i) != 0 {
+                *p = probability;
+            }
+        }
+        distribution
+    }
+
+    /// See [`TruthValuesPowerSet::debug_bits`].
+    pub fn debug_bits(self) -> [bool; 4] {
+        ebelnapian_to_powerset(self).debug_bits()
+    }
+
+    /// See [`TruthValuesPowerSet::debug_str`].
+    pub fn debug_str(self) -> &'static str {
+        ebelnapian_to_powerset(self).debug_str()
+    }
+
+    /// Compares the sets of possible truth values of both values.
+    pub fn agreement(self, other: Self) -> Agreement {
+        let (a, b) = (
+            ebelnapian_to_powerset(self) as u8,
+            ebelnapian_to_powerset(other) as u8,
+        );
+        if a == b {
+            Agreement::Identical
+        } else if a & b != 0 {
+            Agreement::Overlapping
+        } else {
+            Agreement::Disjoint
+        }
+    }
+
+    /// Returns a hash that is stable across runs, platforms, and compiler
+    /// versions (unlike [`core::hash::Hash`], whose output depends on the hasher).
+    ///
+    /// It's obtained by applying the SplitMix64 finalizer to the 4-bit mask of
+    /// the value's possible truth values (bit 0 = Neither, bit 1 = False,
+    /// bit 2 = True, bit 3 = Both).
+    pub fn stable_hash(self) -> u64 {
+        let mut z = (ebelnapian_to_powerset(self) as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// The "wide" interpretation of a [`TernaryTruth`]: `Unknown` means that we
+    /// have no information at all, so it maps to `Unknown(NFTB)`. Classical
+    /// values map to their known counterparts.
+    ///
+    /// The `From<TernaryTruth>` conversion implements the "narrow"
+    /// interpretation instead, mapping `Unknown` to `Unknown(_FT_)`.
+    pub fn from_ternary_wide(value: TernaryTruth) -> Self {
+        match value {
+            TernaryTruth::False => EBelnapian::Known(Belnapian::False),
+            TernaryTruth::True => EBelnapian::Known(Belnapian::True),
+            TernaryTruth::Unknown => EBelnapian::Unknown(Unknown::NFTB),
+        }
+    }
+
+    /// Returns `true` iff converting this value into a [`TernaryTruth`] and back
+    /// yields the same value, that is, for `Known(False)`, `Known(True)`, and
+    /// `Unknown(_FT_)`. Every other value has no faithful ternary counterpart.
+    pub fn is_faithful_ternary(self) -> bool {
+        match self {
+            EBelnapian::Known(Belnapian::False)
+            | EBelnapian::Known(Belnapian::True)
+            | EBelnapian::Unknown(Unknown::_FT_) => true,
+            _ => false,
+        }
+    }
+
     pub fn and(self, other: Self) -> Self {
         match (self, other) {
             (EBelnapian::Known(a), EBelnapian::Known(b)) => EBelnapian::Known(a.and(b)),
@@ -1474,6 +3838,47 @@ impl EBelnapian {
         }
     }
 
+    /// Belnap's conflation, see [`Belnapian::conflate`].
+    pub fn conflate(self) -> Self {
+        match self {
+            EBelnapian::Known(value) => EBelnapian::Known(value.conflate()),
+            EBelnapian::Unknown(value) => EBelnapian::Unknown(value.conflate()),
+        }
+    }
+
+    /// Material implication, `¬a ∨ b`. It's computed through dedicated tables
+    /// (see [`Unknown::implies`]), but it always agrees with
+    /// `self.not().or(other)`.
+    pub fn implies(self, other: Self) -> Self {
+        match (self, other) {
+            (EBelnapian::Known(a), EBelnapian::Known(b)) => EBelnapian::Known(a.implies(b)),
+            (EBelnapian::Unknown(a), EBelnapian::Unknown(b)) => a.implies(b),
+            (EBelnapian::Known(a), EBelnapian::Unknown(b)) => implies_ebelnapian_unknown(a, b),
+            (EBelnapian::Unknown(a), EBelnapian::Known(b)) => implies_unknown_ebelnapian(a, b),
+        }
+    }
+
+    /// Computed by lifting [`Belnapian::xor`] over every pair of possible
+    /// values of the operands.
+    pub fn xor(self, other: Self) -> Self {
+        match (self, other) {
+            (EBelnapian::Known(a), EBelnapian::Known(b)) => EBelnapian::Known(a.xor(b)),
+            (EBelnapian::Unknown(a), EBelnapian::Unknown(b)) => a.xor(b),
+            (EBelnapian::Known(a), EBelnapian::Unknown(b)) => xor_ebelnapian_unknown(a, b),
+            (EBelnapian::Unknown(a), EBelnapian::Known(b)) => xor_ebelnapian_unknown(b, a),
+        }
+    }
+
+    /// Defined as `¬(a ∧ b)`.
+    pub fn nand(self, other: Self) -> Self {
+        self.and(other).not()
+    }
+
+    /// Defined as `¬(a ∨ b)`.
+    pub fn nor(self, other: Self) -> Self {
+        self.or(other).not()
+    }
+
     pub fn superposition(self, other: Self) -> Self {
         match (self, other) {
             (EBelnapian::Known(a), EBelnapian::Known(b)) => EBelnapian::Known(a.superposition(b)),
@@ -1496,14 +3901,41 @@ impl EBelnapian {
         }
     }
 
+    /// The knowledge meet of Belnap's bilattice, lifted to possibility sets.
+    /// The same as [`EBelnapian::annihilation`], see [`Unknown::knowledge_meet`].
+    pub fn knowledge_meet(self, other: Self) -> Self {
+        self.annihilation(other)
+    }
+
+    /// The knowledge join of Belnap's bilattice, lifted to possibility sets.
+    /// The same as [`EBelnapian::superposition`], see
+    /// [`Unknown::knowledge_join`].
+    pub fn knowledge_join(self, other: Self) -> Self {
+        self.superposition(other)
+    }
+
     pub fn eq(self, other: Self) -> Self {
         match (self, other) {
-            (EBelnapian::Known(a), EBelnapian::Known(b)) => EBelnapian::Known((a == b).into()),
+            (EBelnapian::Known(a), EBelnapian::Known(b)) => EBelnapian::Known(a.eq(b)),
             (EBelnapian::Unknown(a), EBelnapian::Unknown(b)) => a.eq(b),
             (EBelnapian::Known(a), EBelnapian::Unknown(b)) => eq_ebelnapian_unknown(a, b),
             (EBelnapian::Unknown(a), EBelnapian::Known(b)) => eq_ebelnapian_unknown(b, a),
         }
     }
+
+    /// Returns the most specific value that admits every possibility admitted
+    /// by either `self` or `other` (the union of their possibility sets). This
+    /// is the join of the possibility lattice.
+    ///
+    /// Two different known values generalize to an unknown value, e.g.
+    /// `Known(True).generalize(Known(False)) == Unknown(_FT_)`.
+    pub fn generalize(self, other: Self) -> Self {
+        let mask = ebelnapian_to_powerset(self) as u8 | ebelnapian_to_powerset(other) as u8;
+        match powerset_to_ebelnapian(powerset_from_mask(mask)) {
+            Some(value) => value,
+            None => unreachable!(), // The union of two non-empty sets is never empty
+        }
+    }
 }
 
 impl AndOp for EBelnapian {
@@ -1518,230 +3950,357 @@ impl OrOp for EBelnapian {
     }
 }
 
-impl ops::Not for EBelnapian {
-    type Output = Self;
+impl XorOp for EBelnapian {
+    fn xor(self, other: Self) -> Self {
+        self.xor(other)
+    }
+}
 
-    fn not(self) -> Self {
-        self.not()
+impl ImpliesOp for EBelnapian {
+    fn implies(self, other: Self) -> Self {
+        self.implies(other)
     }
 }
 
-impl LogicOperand for EBelnapian {}
+impl NandOp for EBelnapian {
+    fn nand(self, other: Self) -> Self {
+        self.nand(other)
+    }
+}
 
-// Conversions
-// -----------------------------------------------------------------------------
-// Bool:
+impl NorOp for EBelnapian {
+    fn nor(self, other: Self) -> Self {
+        self.nor(other)
+    }
+}
 
-impl From<bool> for Belnapian {
-    fn from(value: bool) -> Self {
-        match value {
-            false => Belnapian::False,
-            true => Belnapian::True,
-        }
+impl NotOp for EBelnapian {
+    fn not(self) -> Self {
+        self.not()
     }
 }
 
-impl TryFrom<Belnapian> for bool {
-    type Error = ();
+impl ops::Not for EBelnapian {
+    type Output = Self;
 
-    fn try_from(value: Belnapian) -> Result<bool, Self::Error> {
-        match value {
-            Belnapian::False => Ok(false),
-            Belnapian::True => Ok(true),
-            _ => Err(()), // TODO: Improve?
-        }
+    fn not(self) -> Self {
+        self.not()
     }
 }
 
-impl From<bool> for TernaryTruth {
-    fn from(value: bool) -> Self {
-        match value {
-            false => TernaryTruth::False,
-            true => TernaryTruth::True,
-        }
+/// `a >> b` is the logical implication `a.implies(b)` (material
+/// implication), **not** a bit shift.
+impl ops::Shr for EBelnapian {
+    type Output = Self;
+
+    fn shr(self, other: Self) -> Self {
+        self.implies(other)
     }
 }
 
-impl TryFrom<TernaryTruth> for bool {
-    type Error = ();
+impl LogicOperand for EBelnapian {}
 
-    fn try_from(value: TernaryTruth) -> Result<bool, Self::Error> {
-        match value {
-            TernaryTruth::False => Ok(false),
-            TernaryTruth::True => Ok(true),
-            _ => Err(()), // TODO: Improve?
+// Display Impls
+// -----------------------------------------------------------------------------
+// `Debug` keeps the variant names; `Display` spells out the members of the
+// unknown values, e.g. `Unknown::NF__` is displayed as `{Neither,False}`.
+
+impl fmt::Display for Belnapian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Belnapian::Neither => f.write_str("Neither"),
+            Belnapian::False => f.write_str("False"),
+            Belnapian::True => f.write_str("True"),
+            Belnapian::Both => f.write_str("Both"),
         }
     }
 }
 
-impl From<bool> for EBelnapian {
-    fn from(value: bool) -> Self {
-        match value {
-            false => EBelnapian::Known(Belnapian::False),
-            true => EBelnapian::Known(Belnapian::True),
+impl fmt::Display for TernaryTruth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TernaryTruth::False => f.write_str("False"),
+            TernaryTruth::True => f.write_str("True"),
+            TernaryTruth::Unknown => f.write_str("Unknown"),
         }
     }
 }
 
-impl TryFrom<EBelnapian> for bool {
-    type Error = ();
+impl fmt::Display for Unknown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, value) in TruthValuesPowerSet::from(*self).members().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{value}")?;
+        }
+        f.write_str("}")
+    }
+}
 
-    fn try_from(value: EBelnapian) -> Result<bool, Self::Error> {
-        match value {
-            EBelnapian::Known(Belnapian::False) => Ok(false),
-            EBelnapian::Known(Belnapian::True) => Ok(true),
-            _ => Err(()), // TODO: Improve?
+impl fmt::Display for EBelnapian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EBelnapian::Known(value) => write!(f, "{value}"),
+            EBelnapian::Unknown(value) => write!(f, "Unknown{value}"),
         }
     }
 }
 
+// Inference Free Functions
 // -----------------------------------------------------------------------------
-// TernaryTruth:
 
-impl TryFrom<Belnapian> for TernaryTruth {
-    type Error = ();
+/// Computes the truth value of the resolvent of two clauses, where each clause
+/// is given as the truth values of its literals under some assignment.
+///
+/// In classical logic, resolving `p ∨ A` with `¬p ∨ B` drops the complementary
+/// pair and yields `A ∨ B`. In Belnap's logic `p ∨ ¬p` is not a tautology
+/// (`Neither ∨ Neither` is `Neither`), and a `Both` literal already makes its
+/// clause designated, so dropping the pair would not be sound. Hence we keep
+/// every literal: the result is the disjunction of all the literals of both
+/// clauses, starting from `False` (so two empty clauses resolve to `False`).
+pub fn resolve(clause_a: &[Belnapian], clause_b: &[Belnapian]) -> Belnapian {
+    clause_a
+        .iter()
+        .chain(clause_b)
+        .fold(Belnapian::False, |acc, literal| acc.or(*literal))
+}
 
-    fn try_from(value: Belnapian) -> Result<TernaryTruth, Self::Error> {
-        match value {
-            Belnapian::False => Ok(TernaryTruth::False),
-            Belnapian::True => Ok(TernaryTruth::True),
-            _ => Err(()), // TODO: Improve?
+/// Iterates `x = x.superposition(f(x))` from `start` until it stabilizes, and
+/// returns the reached value.
+///
+/// Each step can only move `x` up in the knowledge order, whose height is 2, so
+/// this always terminates after (at most) 3 evaluations of `f`. If `f` is
+/// monotone in the knowledge order, the result is the least value above `start`
+/// that already contains all the information produced by `f`.
+pub fn kleene_fixpoint(f: impl Fn(Belnapian) -> Belnapian, start: Belnapian) -> Belnapian {
+    let mut x = start;
+    loop {
+        let next = x.superposition(f(x));
+        if next == x {
+            return x;
         }
+        x = next;
     }
 }
 
-impl TryFrom<TernaryTruth> for Belnapian {
-    type Error = ();
+/// Yields the accumulated superposition (knowledge join) of the inputs after
+/// each of them, so the first item is the first input itself and the last item
+/// is the superposition of all of them.
+pub fn superposition_scan(
+    iter: impl IntoIterator<Item = EBelnapian>,
+) -> impl Iterator<Item = EBelnapian> {
+    iter.into_iter()
+        .scan(EBelnapian::Known(Belnapian::Neither), |acc, value| {
+            *acc = acc.superposition(value);
+            Some(*acc)
+        })
+}
 
-    fn try_from(value: TernaryTruth) -> Result<Belnapian, Self::Error> {
-        match value {
-            TernaryTruth::False => Ok(Belnapian::False),
-            TernaryTruth::True => Ok(Belnapian::True),
-            _ => Err(()), // TODO: Improve?
-        }
+// Folds Free Functions
+// -----------------------------------------------------------------------------
+
+/// Folds the values with `op`, starting from `identity`, and stops consuming
+/// the iterator as soon as the accumulator equals `absorbing` (if given).
+///
+/// `absorbing` must be an absorbing element of `op` (e.g. `False` for `and`,
+/// `True` for `or`), otherwise stopping early changes the result.
+pub fn fold_with_absorbing(
+    iter: impl IntoIterator<Item = Belnapian>,
+    op: impl Fn(Belnapian, Belnapian) -> Belnapian,
+    identity: Belnapian,
+    absorbing: Option<Belnapian>,
+) -> Belnapian {
+    let mut acc = identity;
+    if absorbing == Some(acc) {
+        return acc;
     }
-}
 
-impl From<TernaryTruth> for EBelnapian {
-    fn from(value: TernaryTruth) -> Self {
-        match value {
-            TernaryTruth::False => EBelnapian::Known(Belnapian::False),
-            TernaryTruth::True => EBelnapian::Known(Belnapian::True),
-            TernaryTruth::Unknown => EBelnapian::Unknown(Unknown::_FT_),
+    for value in iter {
+        acc = op(acc, value);
+        if absorbing == Some(acc) {
+            break;
         }
     }
+    acc
 }
 
-impl TryFrom<EBelnapian> for TernaryTruth {
-    type Error = ();
+// Unary Functions Free Functions
+// -----------------------------------------------------------------------------
 
-    fn try_from(value: EBelnapian) -> Result<TernaryTruth, Self::Error> {
-        match value {
-            EBelnapian::Known(Belnapian::False) => Ok(TernaryTruth::False),
-            EBelnapian::Known(Belnapian::True) => Ok(TernaryTruth::True),
-            EBelnapian::Unknown(Unknown::_FT_) => Ok(TernaryTruth::Unknown),
-            _ => Err(()), // TODO: Improve?
-        }
-    }
+/// Returns the composition `f ∘ g`, that is, `x ↦ f(g(x))`.
+pub fn compose2(
+    f: impl Fn(Belnapian) -> Belnapian,
+    g: impl Fn(Belnapian) -> Belnapian,
+) -> impl Fn(Belnapian) -> Belnapian {
+    move |x| f(g(x))
 }
 
-impl TryFrom<Unknown> for TernaryTruth {
-    type Error = ();
+/// Checks whether [`Belnapian::not`] can be obtained by composing (any number
+/// of times, in any order) the given unary functions.
+///
+/// The search is bounded: there are only `4⁴ = 256` unary functions over
+/// [`Belnapian`], so the set of compositions saturates after, at most, 256
+/// rounds of composing every known function with every other one.
+#[cfg(feature = "alloc")]
+pub fn closure_contains_not(unaries: &[Box<dyn Fn(Belnapian) -> Belnapian>]) -> bool {
+    // Every unary function is identified by its truth table, encoded as a
+    // base-4 number with the image of `Belnapian::ALL[i]` as its i-th digit.
+    fn encode(table: [Belnapian; 4]) -> usize {
+        table
+            .iter()
+            .rev()
+            .fold(0, |code, value| code * 4 + *value as usize)
+    }
 
-    fn try_from(value: Unknown) -> Result<TernaryTruth, Self::Error> {
-        match value {
-            Unknown::_FT_ => Ok(TernaryTruth::Unknown),
-            _ => Err(()), // TODO: Improve?
+    let not_code = encode(Belnapian::ALL.map(Belnapian::not));
+
+    let mut known = [false; 256];
+    let mut tables = [[Belnapian::Neither; 4]; 256];
+    let mut count = 0;
+    for f in unaries {
+        let table = Belnapian::ALL.map(f);
+        if !known[encode(table)] {
+            known[encode(table)] = true;
+            tables[count] = table;
+            count += 1;
         }
     }
-}
 
-impl TryFrom<TernaryTruth> for Unknown {
-    type Error = ();
+    loop {
+        if known[not_code] {
+            return true;
+        }
+
+        let previous_count = count;
+        for i in 0..previous_count {
+            for j in 0..previous_count {
+                let (f, g) = (tables[i], tables[j]);
+                let table = g.map(|value| f[value as usize]);
+                if !known[encode(table)] {
+                    known[encode(table)] = true;
+                    tables[count] = table;
+                    count += 1;
+                }
+            }
+        }
 
-    fn try_from(value: TernaryTruth) -> Result<Unknown, Self::Error> {
-        match value {
-            TernaryTruth::Unknown => Ok(Unknown::_FT_),
-            _ => Err(()), // TODO: Improve?
+        if count == previous_count {
+            return false;
         }
     }
 }
 
+// Bilattice Free Functions
 // -----------------------------------------------------------------------------
-// Belnapian:
 
-impl From<Belnapian> for EBelnapian {
-    fn from(value: Belnapian) -> Self {
-        EBelnapian::Known(value)
+/// Returns the nodes and covering edges of the knowledge order (`Neither` at
+/// the bottom, `Both` at the top) and of the truth order (`False` at the
+/// bottom, `True` at the top).
+pub const fn bilattice_structure() -> BilatticeStructure {
+    BilatticeStructure {
+        nodes: [
+            Belnapian::Neither,
+            Belnapian::False,
+            Belnapian::True,
+            Belnapian::Both,
+        ],
+        knowledge_edges: [
+            (Belnapian::Neither, Belnapian::False),
+            (Belnapian::Neither, Belnapian::True),
+            (Belnapian::False, Belnapian::Both),
+            (Belnapian::True, Belnapian::Both),
+        ],
+        truth_edges: [
+            (Belnapian::False, Belnapian::Neither),
+            (Belnapian::False, Belnapian::Both),
+            (Belnapian::Neither, Belnapian::True),
+            (Belnapian::Both, Belnapian::True),
+        ],
     }
 }
 
-impl TryFrom<EBelnapian> for Belnapian {
-    type Error = ();
+// Algebraic Laws Free Functions
+// -----------------------------------------------------------------------------
 
-    fn try_from(value: EBelnapian) -> Result<Belnapian, Self::Error> {
-        match value {
-            EBelnapian::Known(value) => Ok(value),
-            _ => Err(()), // TODO: Improve?
+/// Checks whether `and` distributes over `or` for every triple of `values`,
+/// that is, whether `a ∧ (b ∨ c) == (a ∧ b) ∨ (a ∧ c)`. Returns the first
+/// counterexample `(a, b, c)`, or `None` if the law holds.
+///
+/// Belnap's bilattice is distributive, so this returns `None` for
+/// [`Belnapian`] (and for [`TernaryTruth`]). It does return a counterexample for
+/// [`EBelnapian`]: its operations treat both operands as independent unknowns,
+/// so repeating `a` in `(a ∧ b) ∨ (a ∧ c)` loses the correlation between both
+/// occurrences (e.g. `a = Unknown(_FT_)`, `b = Known(Neither)`,
+/// `c = Known(Both)` gives `Unknown(_FT_)` on the left and `Unknown(NFTB)` on
+/// the right). That is an over-approximation, not a table bug.
+pub fn check_distributive<T: TruthValue + PartialEq>(
+    values: &[T],
+    and: impl Fn(T, T) -> T,
+    or: impl Fn(T, T) -> T,
+) -> Option<(T, T, T)> {
+    for &a in values {
+        for &b in values {
+            for &c in values {
+                if and(a, or(b, c)) != or(and(a, b), and(a, c)) {
+                    return Some((a, b, c));
+                }
+            }
         }
     }
+    None
 }
 
+// Truth Tables Free Functions
 // -----------------------------------------------------------------------------
-// Unknown:
 
-impl From<Unknown> for EBelnapian {
-    fn from(value: Unknown) -> Self {
-        EBelnapian::Unknown(value)
-    }
+/// Returns the truth table of `op` over `domain`, as `(a, op(a))` rows in the
+/// order of `domain`.
+#[cfg(feature = "alloc")]
+pub fn truth_table_unary<T: Copy>(op: impl Fn(T) -> T, domain: &[T]) -> Vec<(T, T)> {
+    domain.iter().map(|&a| (a, op(a))).collect()
 }
 
-impl TryFrom<EBelnapian> for Unknown {
-    type Error = ();
-
-    fn try_from(value: EBelnapian) -> Result<Unknown, Self::Error> {
-        match value {
-            EBelnapian::Unknown(value) => Ok(value),
-            _ => Err(()), // TODO: Improve?
-        }
-    }
+/// Returns the truth table of `op` over `domain`, as `(a, b, op(a, b))` rows,
+/// with `a` varying slowest.
+///
+/// ```
+/// use belnapian::{truth_table_binary, Belnapian};
+///
+/// let table = truth_table_binary(Belnapian::and, &Belnapian::ALL);
+/// assert_eq!(16, table.len());
+/// assert_eq!((Belnapian::Neither, Belnapian::Both, Belnapian::False), table[3]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn truth_table_binary<T: Copy>(op: impl Fn(T, T) -> T, domain: &[T]) -> Vec<(T, T, T)> {
+    let op = &op;
+    domain
+        .iter()
+        .flat_map(|&a| domain.iter().map(move |&b| (a, b, op(a, b))))
+        .collect()
 }
 
-impl From<Unknown> for TruthValuesPowerSet {
-    fn from(value: Unknown) -> Self {
-        match value {
-            Unknown::NF__ => TruthValuesPowerSet::NF__,
-            Unknown::N_T_ => TruthValuesPowerSet::N_T_,
-            Unknown::_FT_ => TruthValuesPowerSet::_FT_,
-            Unknown::NFT_ => TruthValuesPowerSet::NFT_,
-            Unknown::N__B => TruthValuesPowerSet::N__B,
-            Unknown::_F_B => TruthValuesPowerSet::_F_B,
-            Unknown::NF_B => TruthValuesPowerSet::NF_B,
-            Unknown::__TB => TruthValuesPowerSet::__TB,
-            Unknown::N_TB => TruthValuesPowerSet::N_TB,
-            Unknown::_FTB => TruthValuesPowerSet::_FTB,
-            Unknown::NFTB => TruthValuesPowerSet::NFTB,
-        }
+/// Writes the rows of a [`truth_table_unary`] table, one `a | result` line per
+/// row, using the `Display` impls of the values.
+pub fn write_truth_table_unary<T: fmt::Display>(
+    out: &mut impl fmt::Write,
+    rows: &[(T, T)],
+) -> fmt::Result {
+    for (a, result) in rows {
+        writeln!(out, "{a} | {result}")?;
     }
+    Ok(())
 }
 
-impl TryFrom<TruthValuesPowerSet> for Unknown {
-    type Error = ();
-
-    fn try_from(value: TruthValuesPowerSet) -> Result<Unknown, Self::Error> {
-        match value {
-            TruthValuesPowerSet::NF__ => Ok(Unknown::NF__),
-            TruthValuesPowerSet::N_T_ => Ok(Unknown::N_T_),
-            TruthValuesPowerSet::_FT_ => Ok(Unknown::_FT_),
-            TruthValuesPowerSet::NFT_ => Ok(Unknown::NFT_),
-            TruthValuesPowerSet::N__B => Ok(Unknown::N__B),
-            TruthValuesPowerSet::_F_B => Ok(Unknown::_F_B),
-            TruthValuesPowerSet::NF_B => Ok(Unknown::NF_B),
-            TruthValuesPowerSet::__TB => Ok(Unknown::__TB),
-            TruthValuesPowerSet::N_TB => Ok(Unknown::N_TB),
-            TruthValuesPowerSet::_FTB => Ok(Unknown::_FTB),
-            TruthValuesPowerSet::NFTB => Ok(Unknown::NFTB),
-            _ => Err(()), // TODO: Improve?
-        }
+/// Writes the rows of a [`truth_table_binary`] table, one `a | b | result`
+/// line per row, using the `Display` impls of the values.
+pub fn write_truth_table_binary<T: fmt::Display>(
+    out: &mut impl fmt::Write,
+    rows: &[(T, T, T)],
+) -> fmt::Result {
+    for (a, b, result) in rows {
+        writeln!(out, "{a} | {b} | {result}")?;
     }
+    Ok(())
 }
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod belnapian_tests {
-    use belnapian::Belnapian;
+    use belnapian::{
+        Belnapian, BelnapianSlice, Connective, ConversionError, EBelnapian, KnowledgeOrder,
+        Symmetry, TernaryTruth, TruthOrder, TruthValuesPowerSet, Unknown,
+    };
 
     #[test]
     fn test_and() {
@@ -204,6 +207,448 @@ mod belnapian_tests {
         );
     }
 
+    #[test]
+    fn test_implication_operator() {
+        assert_eq!(Belnapian::False, Belnapian::True >> Belnapian::False);
+
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a.implies(b), a >> b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_implies() {
+        assert_eq!(
+            Belnapian::Neither,
+            Belnapian::Neither.implies(Belnapian::False)
+        );
+        assert_eq!(Belnapian::True, Belnapian::Both.implies(Belnapian::True));
+        assert_eq!(
+            Belnapian::True,
+            Belnapian::False.implies(Belnapian::Neither)
+        );
+        assert_eq!(Belnapian::True, Belnapian::Neither.implies(Belnapian::Both));
+
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a.not().or(b), a.implies(b), "{a:?} → {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_nand_nor() {
+        assert_eq!(Belnapian::True, Belnapian::False.nand(Belnapian::Both));
+        assert_eq!(Belnapian::False, Belnapian::Neither.nor(Belnapian::Both));
+
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(!(a.and(b)), a.nand(b), "{a:?} ⊼ {b:?}");
+                assert_eq!(!(a.or(b)), a.nor(b), "{a:?} ⊽ {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_via_nand() {
+        for a in Belnapian::ALL {
+            assert_eq!(a.not(), a.not_via_nand(), "¬{a:?}");
+
+            for b in Belnapian::ALL {
+                assert_eq!(a.and(b), a.and_via_nand(b), "{a:?} ∧ {b:?}");
+                assert_eq!(a.or(b), a.or_via_nand(b), "{a:?} ∨ {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        for (i, a) in Belnapian::ALL.into_iter().enumerate() {
+            for (j, b) in Belnapian::ALL.into_iter().enumerate() {
+                let expected = if i == j {
+                    Belnapian::True
+                } else {
+                    Belnapian::False
+                };
+                assert_eq!(expected, a.eq(b), "{a:?} = {b:?}");
+                assert_eq!(
+                    EBelnapian::Known(expected),
+                    EBelnapian::Known(a).eq(EBelnapian::Known(b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_classical_eq() {
+        assert!(Belnapian::Neither.classical_eq(Belnapian::Neither));
+        assert!(!Belnapian::Neither.classical_eq(Belnapian::Both));
+        assert_eq!(Belnapian::True, Belnapian::Neither.eq(Belnapian::Neither));
+        assert_eq!(
+            Belnapian::Neither,
+            Belnapian::Neither.iff(Belnapian::Neither)
+        );
+
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a == b, a.classical_eq(b));
+                assert_eq!(Belnapian::from(a.classical_eq(b)), a.eq(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_contrapositive() {
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a.implies(b), a.contrapositive(b), "{a:?} → {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_retract() {
+        assert_eq!(Belnapian::Neither, Belnapian::Neither.retract_true());
+        assert_eq!(Belnapian::False, Belnapian::False.retract_true());
+        assert_eq!(Belnapian::Neither, Belnapian::True.retract_true());
+        assert_eq!(Belnapian::False, Belnapian::Both.retract_true());
+
+        assert_eq!(Belnapian::Neither, Belnapian::Neither.retract_false());
+        assert_eq!(Belnapian::Neither, Belnapian::False.retract_false());
+        assert_eq!(Belnapian::True, Belnapian::True.retract_false());
+        assert_eq!(Belnapian::True, Belnapian::Both.retract_false());
+    }
+
+    #[test]
+    fn test_lattice_orders() {
+        for value in Belnapian::ALL {
+            assert!(Belnapian::Neither.knowledge_le(value));
+            assert!(value.knowledge_le(Belnapian::Both));
+            assert!(Belnapian::False.truth_le(value));
+            assert!(value.truth_le(Belnapian::True));
+        }
+        assert!(!Belnapian::False.knowledge_le(Belnapian::True));
+        assert!(!Belnapian::True.knowledge_le(Belnapian::False));
+        assert!(!Belnapian::Neither.truth_le(Belnapian::Both));
+        assert!(!Belnapian::Both.truth_le(Belnapian::Neither));
+
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                let (meet, join) = (a.truth_meet(b), a.truth_join(b));
+                assert!(meet.truth_le(a) && meet.truth_le(b));
+                assert!(a.truth_le(join) && b.truth_le(join));
+
+                let (meet, join) = (a.knowledge_meet(b), a.knowledge_join(b));
+                assert!(meet.knowledge_le(a) && meet.knowledge_le(b));
+                assert!(a.knowledge_le(join) && b.knowledge_le(join));
+            }
+        }
+    }
+
+    #[test]
+    fn test_order_wrappers() {
+        assert!(KnowledgeOrder(Belnapian::Neither) < KnowledgeOrder(Belnapian::True));
+        assert!(KnowledgeOrder(Belnapian::Both) > KnowledgeOrder(Belnapian::False));
+        assert_eq!(
+            None,
+            KnowledgeOrder(Belnapian::False).partial_cmp(&KnowledgeOrder(Belnapian::True))
+        );
+        assert!(TruthOrder(Belnapian::False) < TruthOrder(Belnapian::Neither));
+        assert!(TruthOrder(Belnapian::True) >= TruthOrder(Belnapian::True));
+
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a.truth_le(b), TruthOrder(a) <= TruthOrder(b));
+                assert_eq!(a.knowledge_le(b), KnowledgeOrder(a) <= KnowledgeOrder(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_heights() {
+        assert_eq!(1, Belnapian::Neither.truth_height());
+        assert_eq!(0, Belnapian::False.truth_height());
+        assert_eq!(2, Belnapian::True.truth_height());
+        assert_eq!(1, Belnapian::Both.truth_height());
+
+        assert_eq!(0, Belnapian::Neither.knowledge_height());
+        assert_eq!(1, Belnapian::False.knowledge_height());
+        assert_eq!(1, Belnapian::True.knowledge_height());
+        assert_eq!(2, Belnapian::Both.knowledge_height());
+    }
+
+    #[test]
+    fn test_is_designated() {
+        assert!(!Belnapian::Neither.is_designated());
+        assert!(!Belnapian::False.is_designated());
+        assert!(Belnapian::True.is_designated());
+        assert!(Belnapian::Both.is_designated());
+
+        for value in Belnapian::ALL {
+            assert_eq!(
+                value.is_designated(),
+                value.is_designated_in(TruthValuesPowerSet::__TB)
+            );
+            assert!(!value.is_designated_in(TruthValuesPowerSet::____));
+        }
+        assert!(Belnapian::True.is_designated_in(TruthValuesPowerSet::__T_));
+        assert!(!Belnapian::Both.is_designated_in(TruthValuesPowerSet::__T_));
+        assert!(Belnapian::Neither.is_designated_in(TruthValuesPowerSet::N_T_));
+
+        assert!(TernaryTruth::True.is_designated());
+        assert!(!TernaryTruth::False.is_designated());
+        assert!(!TernaryTruth::Unknown.is_designated());
+    }
+
+    #[test]
+    fn test_round_to_designated() {
+        assert_eq!(Belnapian::True, Belnapian::Neither.round_to_designated());
+        assert_eq!(Belnapian::Both, Belnapian::False.round_to_designated());
+        assert_eq!(Belnapian::True, Belnapian::True.round_to_designated());
+        assert_eq!(Belnapian::Both, Belnapian::Both.round_to_designated());
+
+        for value in Belnapian::ALL {
+            assert_eq!(
+                value.round_to_designated(),
+                value.superposition(Belnapian::True)
+            );
+        }
+    }
+
+    #[test]
+    fn test_vote() {
+        use Belnapian::{Both, False, Neither, True};
+
+        // Empty and abstaining inputs.
+        assert_eq!(Neither, Belnapian::vote(&[]));
+        assert_eq!(Neither, Belnapian::vote(&[Neither, Neither]));
+
+        // Unanimous inputs.
+        assert_eq!(True, Belnapian::vote(&[True, True, True]));
+        assert_eq!(False, Belnapian::vote(&[False]));
+        assert_eq!(Both, Belnapian::vote(&[Both, Both]));
+
+        // Ties.
+        assert_eq!(Both, Belnapian::vote(&[True, False]));
+        assert_eq!(Both, Belnapian::vote(&[True, Neither, False, Both]));
+        assert_eq!(Both, Belnapian::vote(&[Both, True, False]));
+
+        // Majorities, with `Neither` abstaining and `Both` voting twice.
+        assert_eq!(True, Belnapian::vote(&[True, True, False, Neither]));
+        assert_eq!(False, Belnapian::vote(&[Neither, False, Neither]));
+        assert_eq!(True, Belnapian::vote(&[Both, True]));
+        assert_eq!(False, Belnapian::vote(&[Both, False]));
+    }
+
+    #[test]
+    fn test_from_weights() {
+        assert_eq!(None, Belnapian::from_weights([0.0; 4]));
+        assert_eq!(None, Belnapian::from_weights([1.0, -1.0, 1.0, 1.0]));
+        assert_eq!(None, Belnapian::from_weights([1.0, f64::NAN, 1.0, 1.0]));
+
+        let noisy = Belnapian::from_weights([1.0, 0.0, 2.0, 1.0]).unwrap();
+        assert_eq!([0.25, 0.0, 0.5, 0.25], noisy.probabilities());
+        assert_eq!(Belnapian::Neither, noisy.sample(-1.0));
+        assert_eq!(Belnapian::Both, noisy.sample(1.0));
+
+        // A small LCG is enough to check that every possible value shows up
+        // with roughly the expected frequency.
+        let mut state: u32 = 0x2545_f491;
+        let mut counts = [0usize; 4];
+        for _ in 0..10_000 {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let u = (state >> 8) as f64 / (1u32 << 24) as f64;
+            counts[noisy.sample(u) as usize] += 1;
+        }
+        assert_eq!(0, counts[Belnapian::False as usize]);
+        assert!((2_200..2_800).contains(&counts[Belnapian::Neither as usize]));
+        assert!((4_700..5_300).contains(&counts[Belnapian::True as usize]));
+        assert!((2_200..2_800).contains(&counts[Belnapian::Both as usize]));
+    }
+
+    #[test]
+    fn test_conflate() {
+        assert_eq!(Belnapian::Both, Belnapian::Neither.conflate());
+        assert_eq!(Belnapian::False, Belnapian::False.conflate());
+        assert_eq!(Belnapian::True, Belnapian::True.conflate());
+        assert_eq!(Belnapian::Neither, Belnapian::Both.conflate());
+
+        for value in Belnapian::ALL {
+            assert_eq!(value, value.conflate().conflate());
+            assert_eq!(value.not().conflate(), value.conflate().not());
+            assert_eq!(value.apply_symmetry(Symmetry::Conflation), value.conflate());
+        }
+
+        for value in EBelnapian::ALL {
+            assert_eq!(value, value.conflate().conflate());
+            assert_eq!(value.not().conflate(), value.conflate().not());
+
+            let conflated: TruthValuesPowerSet = TruthValuesPowerSet::from(value)
+                .members()
+                .map(Belnapian::conflate)
+                .collect();
+            assert_eq!(conflated, value.conflate().into());
+        }
+    }
+
+    #[test]
+    fn test_apply_symmetry() {
+        let symmetries = [
+            Symmetry::Identity,
+            Symmetry::Negation,
+            Symmetry::Conflation,
+            Symmetry::NegationConflation,
+        ];
+
+        for sym in symmetries {
+            for a in Belnapian::ALL {
+                for b in Belnapian::ALL {
+                    if a != b {
+                        assert_ne!(a.apply_symmetry(sym), b.apply_symmetry(sym));
+                    }
+                }
+            }
+        }
+
+        for value in Belnapian::ALL {
+            assert_eq!(value, value.apply_symmetry(Symmetry::Identity));
+            assert_eq!(!value, value.apply_symmetry(Symmetry::Negation));
+            assert_eq!(
+                value.apply_symmetry(Symmetry::NegationConflation),
+                value
+                    .apply_symmetry(Symmetry::Negation)
+                    .apply_symmetry(Symmetry::Conflation)
+            );
+            assert_eq!(
+                value.apply_symmetry(Symmetry::NegationConflation),
+                value
+                    .apply_symmetry(Symmetry::Conflation)
+                    .apply_symmetry(Symmetry::Negation)
+            );
+        }
+    }
+
+    #[test]
+    fn test_support_rejection() {
+        assert_eq!((0, 0), Belnapian::support_rejection(&[]));
+        assert_eq!(
+            (3, 2),
+            Belnapian::support_rejection(&[
+                Belnapian::True,
+                Belnapian::Neither,
+                Belnapian::Both,
+                Belnapian::False,
+                Belnapian::True,
+                Belnapian::Neither,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_functions_matching() {
+        assert_eq!(4u64.pow(16), Belnapian::functions_matching(&[]));
+
+        let and_table: Vec<_> = Belnapian::ALL
+            .into_iter()
+            .flat_map(|a| Belnapian::ALL.map(|b| ((a, b), a.and(b))))
+            .collect();
+        assert_eq!(1, Belnapian::functions_matching(&and_table));
+        assert_eq!(4, Belnapian::functions_matching(&and_table[1..]));
+
+        let f_and_t = ((Belnapian::False, Belnapian::True), Belnapian::False);
+        assert_eq!(4u64.pow(15), Belnapian::functions_matching(&[f_and_t]));
+        assert_eq!(
+            4u64.pow(15),
+            Belnapian::functions_matching(&[f_and_t, f_and_t])
+        );
+        assert_eq!(
+            0,
+            Belnapian::functions_matching(&[
+                f_and_t,
+                ((Belnapian::False, Belnapian::True), Belnapian::True)
+            ])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_distinguishing_ops() {
+        let ops = Belnapian::distinguishing_ops(Belnapian::Neither, Belnapian::Both);
+        assert!(ops.contains(&Connective::And));
+        assert!(ops.contains(&Connective::Superposition));
+
+        assert_eq!(
+            Connective::ALL.to_vec(),
+            Belnapian::distinguishing_ops(Belnapian::False, Belnapian::True)
+        );
+        assert!(Belnapian::distinguishing_ops(Belnapian::Both, Belnapian::Both).is_empty());
+    }
+
+    #[test]
+    fn test_connective_apply() {
+        assert_eq!(
+            Belnapian::False,
+            Connective::And.apply(Belnapian::Neither, Belnapian::Both)
+        );
+        assert_eq!(
+            Belnapian::Both,
+            Connective::Superposition.apply(Belnapian::False, Belnapian::True)
+        );
+    }
+
+    #[test]
+    fn test_all_ops() {
+        let results = Belnapian::True.all_ops(Belnapian::Both);
+
+        assert_eq!(Belnapian::True.and(Belnapian::Both), results.and);
+        assert_eq!(Belnapian::True.or(Belnapian::Both), results.or);
+        assert_eq!(Belnapian::True.xor(Belnapian::Both), results.xor);
+        assert_eq!(Belnapian::True.implies(Belnapian::Both), results.implies);
+        assert_eq!(Belnapian::True.iff(Belnapian::Both), results.iff);
+        assert_eq!(
+            Belnapian::True.superposition(Belnapian::Both),
+            results.superposition
+        );
+        assert_eq!(
+            Belnapian::True.annihilation(Belnapian::Both),
+            results.annihilation
+        );
+        assert_eq!(Belnapian::False, results.eq);
+        assert_eq!(Belnapian::True, Belnapian::Both.all_ops(Belnapian::Both).eq);
+    }
+
+    #[test]
+    fn test_channels() {
+        assert_eq!(
+            (TernaryTruth::False, TernaryTruth::False),
+            Belnapian::Neither.to_channels()
+        );
+        assert_eq!(
+            (TernaryTruth::False, TernaryTruth::True),
+            Belnapian::False.to_channels()
+        );
+        assert_eq!(
+            (TernaryTruth::True, TernaryTruth::False),
+            Belnapian::True.to_channels()
+        );
+        assert_eq!(
+            (TernaryTruth::True, TernaryTruth::True),
+            Belnapian::Both.to_channels()
+        );
+
+        for value in Belnapian::ALL {
+            let (told_true, told_false) = value.to_channels();
+            assert_eq!(Ok(value), Belnapian::from_channels(told_true, told_false));
+        }
+
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            Belnapian::from_channels(TernaryTruth::Unknown, TernaryTruth::False)
+        );
+    }
+
     #[test]
     fn test_bool_conversions() {
         assert_eq!(Belnapian::False, Belnapian::from(false));
@@ -214,18 +659,145 @@ mod belnapian_tests {
 
         assert_eq!(Ok(false), Belnapian::False.try_into());
         assert_eq!(Ok(true), Belnapian::True.try_into());
+
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            bool::try_from(Belnapian::Neither)
+        );
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            bool::try_from(Belnapian::Both)
+        );
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            TernaryTruth::try_from(Belnapian::Both)
+        );
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            Belnapian::try_from(TernaryTruth::Unknown)
+        );
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            bool::try_from(TernaryTruth::Unknown)
+        );
+        assert_eq!(
+            Err(ConversionError::NotSingleton),
+            Belnapian::try_from(EBelnapian::Unknown(Unknown::_FT_))
+        );
+        assert_eq!(
+            Err(ConversionError::NotUnknown),
+            Unknown::try_from(EBelnapian::Known(Belnapian::True))
+        );
+        assert_eq!(
+            Err(ConversionError::NotUnknown),
+            Unknown::try_from(TernaryTruth::True)
+        );
+        assert_eq!(
+            Err(ConversionError::NotUnknown),
+            Unknown::try_from(TruthValuesPowerSet::__T_)
+        );
+        assert_eq!(
+            Err(ConversionError::EmptySet),
+            Unknown::try_from(TruthValuesPowerSet::____)
+        );
     }
 
     #[test]
     fn test_packing() {
         assert_eq!(1, std::mem::size_of::<Belnapian>());
     }
+
+    #[test]
+    fn test_discriminants() {
+        assert_eq!(0, Belnapian::Neither.as_u8());
+        assert_eq!(1, Belnapian::False.as_u8());
+        assert_eq!(2, Belnapian::True.as_u8());
+        assert_eq!(3, Belnapian::Both.as_u8());
+
+        for value in Belnapian::ALL {
+            assert_eq!(value as u8, value.as_u8());
+            assert_eq!(Some(value), Belnapian::from_u8(value.as_u8()));
+        }
+        for value in 4..=u8::MAX {
+            assert_eq!(None, Belnapian::from_u8(value));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_pack_unpack() {
+        assert_eq!(
+            vec![0b11_10_01_00, 0b10],
+            Belnapian::pack(&[
+                Belnapian::Neither,
+                Belnapian::False,
+                Belnapian::True,
+                Belnapian::Both,
+                Belnapian::True,
+            ])
+        );
+        assert_eq!(Vec::<u8>::new(), Belnapian::pack(&[]));
+        assert_eq!(None, Belnapian::unpack(&[0], 5));
+
+        for len in [0, 1, 3, 4, 7, 9, 18] {
+            let values: Vec<Belnapian> =
+                (0..len).map(|i| Belnapian::ALL[(i * 7 + 2) % 4]).collect();
+            let bytes = Belnapian::pack(&values);
+            assert_eq!(len.div_ceil(4), bytes.len());
+            assert_eq!(Some(values), Belnapian::unpack(&bytes, len));
+        }
+    }
+
+    #[test]
+    fn test_packed_slice() {
+        // Lanes (from the lowest bits): Neither, False, True, Both | Both, True
+        let bytes = [0b11_10_01_00, 0b00_00_10_11];
+
+        let view = BelnapianSlice::new(&bytes, 6).unwrap();
+        assert_eq!(6, view.len());
+        assert!(!view.is_empty());
+        assert_eq!(Some(Belnapian::True), view.get(2));
+        assert_eq!(Some(Belnapian::Both), view.get(4));
+        assert_eq!(None, view.get(6));
+        assert_eq!(
+            vec![
+                Belnapian::Neither,
+                Belnapian::False,
+                Belnapian::True,
+                Belnapian::Both,
+                Belnapian::Both,
+                Belnapian::True,
+            ],
+            view.iter().collect::<Vec<_>>()
+        );
+
+        assert!(BelnapianSlice::new(&bytes, 9).is_none());
+        assert!(BelnapianSlice::new(&[], 0).unwrap().is_empty());
+    }
 }
 
 #[cfg(test)]
 mod ternary_truth_tests {
     use belnapian::*;
 
+    #[test]
+    fn test_option_bool_conversions() {
+        assert_eq!(TernaryTruth::False, TernaryTruth::from(Some(false)));
+        assert_eq!(TernaryTruth::True, TernaryTruth::from(Some(true)));
+        assert_eq!(TernaryTruth::Unknown, TernaryTruth::from(None));
+
+        for value in [None, Some(false), Some(true)] {
+            assert_eq!(value, Option::<bool>::from(TernaryTruth::from(value)));
+        }
+        for value in TernaryTruth::ALL {
+            assert_eq!(value, TernaryTruth::from(Option::<bool>::from(value)));
+        }
+    }
+
     #[test]
     fn test_and() {
         assert_eq!(
@@ -294,6 +866,112 @@ mod ternary_truth_tests {
         assert!(TernaryTruth::Unknown.or(TernaryTruth::Unknown).is_unknown());
     }
 
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let values: HashSet<TernaryTruth> = [
+            TernaryTruth::False,
+            TernaryTruth::True,
+            TernaryTruth::Unknown,
+            TernaryTruth::Unknown,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(3, values.len());
+
+        assert!(TernaryTruth::Unknown == TernaryTruth::Unknown);
+        assert!(TernaryTruth::Unknown.eq(TernaryTruth::Unknown).is_unknown());
+    }
+
+    #[test]
+    fn test_implies() {
+        assert_eq!(
+            Ok(true),
+            TernaryTruth::False.implies(TernaryTruth::False).try_into()
+        );
+        assert_eq!(
+            Ok(true),
+            TernaryTruth::False.implies(TernaryTruth::True).try_into()
+        );
+        assert_eq!(
+            Ok(true),
+            TernaryTruth::False
+                .implies(TernaryTruth::Unknown)
+                .try_into()
+        );
+
+        assert_eq!(
+            Ok(false),
+            TernaryTruth::True.implies(TernaryTruth::False).try_into()
+        );
+        assert_eq!(
+            Ok(true),
+            TernaryTruth::True.implies(TernaryTruth::True).try_into()
+        );
+        assert!(TernaryTruth::True
+            .implies(TernaryTruth::Unknown)
+            .is_unknown());
+
+        assert!(TernaryTruth::Unknown
+            .implies(TernaryTruth::False)
+            .is_unknown());
+        assert_eq!(
+            Ok(true),
+            TernaryTruth::Unknown.implies(TernaryTruth::True).try_into()
+        );
+        assert!(TernaryTruth::Unknown
+            .implies(TernaryTruth::Unknown)
+            .is_unknown());
+
+        for a in TernaryTruth::ALL {
+            for b in TernaryTruth::ALL {
+                assert_eq!(a.not().or(b), a.implies(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_nand_nor() {
+        assert_eq!(
+            TernaryTruth::True,
+            TernaryTruth::False.nand(TernaryTruth::Unknown)
+        );
+        assert_eq!(
+            TernaryTruth::False,
+            TernaryTruth::Unknown.nor(TernaryTruth::True)
+        );
+
+        for a in TernaryTruth::ALL {
+            for b in TernaryTruth::ALL {
+                assert_eq!(!(a.and(b)), a.nand(b), "{a:?} ⊼ {b:?}");
+                assert_eq!(!(a.or(b)), a.nor(b), "{a:?} ⊽ {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lukasiewicz_implies() {
+        assert_eq!(
+            TernaryTruth::True,
+            TernaryTruth::Unknown.lukasiewicz_implies(TernaryTruth::Unknown)
+        );
+        assert_eq!(
+            TernaryTruth::Unknown,
+            TernaryTruth::True.lukasiewicz_implies(TernaryTruth::Unknown)
+        );
+
+        for a in TernaryTruth::ALL {
+            for b in TernaryTruth::ALL {
+                if (a, b) == (TernaryTruth::Unknown, TernaryTruth::Unknown) {
+                    assert_ne!(a.implies(b), a.lukasiewicz_implies(b));
+                } else {
+                    assert_eq!(a.implies(b), a.lukasiewicz_implies(b), "{a:?} → {b:?}");
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_eq() {
         assert_eq!(
@@ -325,35 +1003,2164 @@ mod ternary_truth_tests {
     fn test_packing() {
         assert_eq!(1, std::mem::size_of::<TernaryTruth>());
     }
-}
-
-#[cfg(test)]
-mod truth_values_set_test {
-    use belnapian::*;
 
     #[test]
-    fn test_packing() {
-        assert_eq!(1, std::mem::size_of::<TruthValuesPowerSet>());
+    fn test_implication_operator() {
+        assert_eq!(
+            TernaryTruth::False,
+            TernaryTruth::True >> TernaryTruth::False
+        );
+
+        for a in TernaryTruth::ALL {
+            for b in TernaryTruth::ALL {
+                assert_eq!(a.implies(b), a >> b);
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod unknown_test {
-    use belnapian::*;
+    #[cfg(feature = "alloc")]
+    fn pseudo_random_values(seed: u32, count: usize) -> Vec<TernaryTruth> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                match (state >> 16) % 3 {
+                    0 => TernaryTruth::False,
+                    1 => TernaryTruth::True,
+                    _ => TernaryTruth::Unknown,
+                }
+            })
+            .collect()
+    }
 
     #[test]
-    fn test_packing() {
-        assert_eq!(1, std::mem::size_of::<Unknown>());
+    #[cfg(feature = "alloc")]
+    fn test_pack_unpack() {
+        let values = pseudo_random_values(7, 11);
+        let packed = TernaryTruth::pack(&values);
+
+        assert_eq!(3, packed.len());
+        assert_eq!(Some(values), TernaryTruth::unpack(&packed, 11));
+        assert_eq!(None, TernaryTruth::unpack(&packed, 12));
+        assert_eq!(None, TernaryTruth::unpack(&packed, 13));
     }
-}
 
-#[cfg(test)]
-mod ebelnapian_tests {
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_and_packed() {
+        let a = pseudo_random_values(1, 1001);
+        let b = pseudo_random_values(2, 1001);
+        let (packed_a, packed_b) = (TernaryTruth::pack(&a), TernaryTruth::pack(&b));
+
+        let mut packed_out = vec![0u8; packed_a.len()];
+        ternary_and_packed(&packed_a, &packed_b, &mut packed_out);
+
+        let expected: Vec<TernaryTruth> = a.iter().zip(&b).map(|(x, y)| x.and(*y)).collect();
+        assert_eq!(Some(expected), TernaryTruth::unpack(&packed_out, 1001));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_or_packed() {
+        let a = pseudo_random_values(3, 1001);
+        let b = pseudo_random_values(4, 1001);
+        let (packed_a, packed_b) = (TernaryTruth::pack(&a), TernaryTruth::pack(&b));
+
+        let mut packed_out = vec![0u8; packed_a.len()];
+        ternary_or_packed(&packed_a, &packed_b, &mut packed_out);
+
+        let expected: Vec<TernaryTruth> = a.iter().zip(&b).map(|(x, y)| x.or(*y)).collect();
+        assert_eq!(Some(expected), TernaryTruth::unpack(&packed_out, 1001));
+    }
+}
+
+#[cfg(test)]
+mod truth_values_set_test {
     use belnapian::*;
 
     #[test]
     fn test_packing() {
-        // TODO: find a clean way to pack it into 1 byte
-        assert_eq!(2, std::mem::size_of::<EBelnapian>());
+        assert_eq!(1, std::mem::size_of::<TruthValuesPowerSet>());
+    }
+
+    #[test]
+    fn test_cardinality_and_contains() {
+        assert_eq!(0, TruthValuesPowerSet::____.cardinality());
+        assert_eq!(4, TruthValuesPowerSet::NFTB.cardinality());
+
+        for bits in 0b0000..=0b1111u8 {
+            let set = TruthValuesPowerSet::try_from(bits).unwrap();
+            let flags = [
+                set.could_be_neither(),
+                set.could_be_false(),
+                set.could_be_true(),
+                set.could_be_both(),
+            ];
+            assert_eq!(
+                flags.iter().filter(|flag| **flag).count(),
+                set.cardinality() as usize
+            );
+
+            assert_eq!(flags[0], set.contains(Belnapian::Neither));
+            assert_eq!(flags[1], set.contains(Belnapian::False));
+            assert_eq!(flags[2], set.contains(Belnapian::True));
+            assert_eq!(flags[3], set.contains(Belnapian::Both));
+        }
+    }
+
+    #[test]
+    fn test_members_round_trip() {
+        assert_eq!(None, TruthValuesPowerSet::____.members().next());
+        assert_eq!(
+            vec![
+                Belnapian::Neither,
+                Belnapian::False,
+                Belnapian::True,
+                Belnapian::Both
+            ],
+            TruthValuesPowerSet::NFTB.members().collect::<Vec<_>>()
+        );
+
+        for bits in 0b0000..=0b1111u8 {
+            let set = TruthValuesPowerSet::try_from(bits).unwrap();
+            assert_eq!(set, set.members().collect());
+        }
+    }
+
+    #[test]
+    fn test_from_iter() {
+        assert_eq!(
+            TruthValuesPowerSet::_FT_,
+            [Belnapian::False, Belnapian::True].into_iter().collect()
+        );
+        assert_eq!(
+            TruthValuesPowerSet::_FT_,
+            [
+                Belnapian::True,
+                Belnapian::False,
+                Belnapian::True,
+                Belnapian::True
+            ]
+            .into_iter()
+            .collect()
+        );
+        assert_eq!(
+            TruthValuesPowerSet::NFTB,
+            [
+                Belnapian::Both,
+                Belnapian::Neither,
+                Belnapian::True,
+                Belnapian::False
+            ]
+            .into_iter()
+            .collect()
+        );
+        assert_eq!(
+            TruthValuesPowerSet::____,
+            std::iter::empty::<Belnapian>().collect()
+        );
+
+        assert_eq!(
+            TruthValuesPowerSet::N__B,
+            TruthValuesPowerSet::N___.insert(Belnapian::Both)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::N___,
+            TruthValuesPowerSet::N___.insert(Belnapian::Neither)
+        );
+    }
+
+    #[test]
+    fn test_members() {
+        for bits in 0b0000..=0b1111u8 {
+            let set = TruthValuesPowerSet::try_from(bits).unwrap();
+            let members = set.members();
+            assert_eq!(set.len(), members.len());
+            assert_eq!((set.len(), Some(set.len())), members.size_hint());
+            assert_eq!(bits.count_ones() as usize, set.len());
+        }
+
+        let mut members = TruthValuesPowerSet::N_TB.members();
+        assert_eq!(Some(Belnapian::Neither), members.next());
+        assert_eq!(2, members.len());
+
+        let mut buffer = [Belnapian::Neither; 4];
+        let mut count = 0;
+        for (slot, value) in buffer.iter_mut().zip(TruthValuesPowerSet::_FTB.members()) {
+            *slot = value;
+            count += 1;
+        }
+        assert_eq!(3, count);
+        assert_eq!(
+            [Belnapian::False, Belnapian::True, Belnapian::Both],
+            buffer[..count]
+        );
+        assert_eq!(0, TruthValuesPowerSet::____.members().count());
+    }
+
+    #[test]
+    fn test_logic_operations() {
+        let sets: Vec<TruthValuesPowerSet> = (0b0000..=0b1111u8)
+            .map(|bits| TruthValuesPowerSet::try_from(bits).unwrap())
+            .collect();
+
+        for a in sets.iter().copied() {
+            assert_eq!(TruthValuesPowerSet::____, a.and(TruthValuesPowerSet::____));
+            assert_eq!(TruthValuesPowerSet::____, TruthValuesPowerSet::____.or(a));
+            assert_eq!(a, a.not().not());
+            assert_eq!(a.len(), a.not().len());
+
+            for b in sets.iter().copied() {
+                if let (Ok(ua), Ok(ub)) = (Unknown::try_from(a), Unknown::try_from(b)) {
+                    assert_eq!(TruthValuesPowerSet::from(ua.and(ub)), a.and(b));
+                    assert_eq!(TruthValuesPowerSet::from(ua.or(ub)), a.or(b));
+                }
+            }
+        }
+
+        assert_eq!(
+            TruthValuesPowerSet::_F__,
+            TruthValuesPowerSet::N___.and(TruthValuesPowerSet::___B)
+        );
+        assert_eq!(TruthValuesPowerSet::_FT_, TruthValuesPowerSet::_FT_.not());
+        assert_eq!(TruthValuesPowerSet::____, TruthValuesPowerSet::____.not());
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        assert_eq!(
+            TruthValuesPowerSet::NFTB,
+            TruthValuesPowerSet::NF__.union(TruthValuesPowerSet::__TB)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::_FT_,
+            TruthValuesPowerSet::NFT_.intersection(TruthValuesPowerSet::_FTB)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::N___,
+            TruthValuesPowerSet::NFT_.difference(TruthValuesPowerSet::_FTB)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::____,
+            TruthValuesPowerSet::N__B.intersection(TruthValuesPowerSet::_FT_)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::N__B,
+            TruthValuesPowerSet::_FT_.complement()
+        );
+        assert_eq!(
+            TruthValuesPowerSet::NFTB,
+            TruthValuesPowerSet::____.complement()
+        );
+
+        for a_bits in 0b0000..=0b1111u8 {
+            let a = TruthValuesPowerSet::try_from(a_bits).unwrap();
+            assert_eq!(a, a.union(TruthValuesPowerSet::____));
+            assert_eq!(a, a.intersection(TruthValuesPowerSet::NFTB));
+            assert_eq!(a, a.complement().complement());
+            assert_eq!(TruthValuesPowerSet::____, a.difference(a));
+
+            for b_bits in 0b0000..=0b1111u8 {
+                let b = TruthValuesPowerSet::try_from(b_bits).unwrap();
+                assert_eq!(a, a.union(a.intersection(b)));
+                assert_eq!(a, a.intersection(a.union(b)));
+                assert_eq!(a.difference(b), a.intersection(b.complement()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        assert_eq!(
+            TruthValuesPowerSet::_FT_,
+            TruthValuesPowerSet::NF__.symmetric_difference(TruthValuesPowerSet::N_T_)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::NFTB,
+            TruthValuesPowerSet::N__B.symmetric_difference(TruthValuesPowerSet::_FT_)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::__T_,
+            TruthValuesPowerSet::____.symmetric_difference(TruthValuesPowerSet::__T_)
+        );
+
+        for bits in 0b0000..=0b1111u8 {
+            let set = TruthValuesPowerSet::try_from(bits).unwrap();
+            assert_eq!(TruthValuesPowerSet::____, set.symmetric_difference(set));
+        }
+    }
+
+    #[test]
+    fn test_singletons() {
+        assert_eq!(
+            TruthValuesPowerSet::N___,
+            TruthValuesPowerSet::from(Belnapian::Neither)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::___B,
+            TruthValuesPowerSet::from(Belnapian::Both)
+        );
+
+        for value in Belnapian::ALL {
+            assert_eq!(Ok(value), TruthValuesPowerSet::from(value).try_into());
+        }
+        for set in TruthValuesPowerSet::ALL {
+            if let Ok(value) = Belnapian::try_from(set) {
+                assert_eq!(set, value.into());
+            } else {
+                assert_ne!(1, set.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitset_codec() {
+        for bits in 0b0000..=0b1111u8 {
+            let set = TruthValuesPowerSet::from_bitset_checked(bits).unwrap();
+            assert_eq!(bits, set.to_bits());
+            assert!(set.validate());
+            assert_eq!(Ok(set), TruthValuesPowerSet::try_from(bits));
+        }
+        assert_eq!(
+            Ok(TruthValuesPowerSet::_FT_),
+            TruthValuesPowerSet::from_bitset_checked(0b0110)
+        );
+
+        for bits in [0b1_0000u8, 0b1_0110, 0b1000_0000, u8::MAX] {
+            assert_eq!(
+                Err(ConversionError::OutOfRange),
+                TruthValuesPowerSet::from_bitset_checked(bits)
+            );
+            assert_eq!(
+                Err(ConversionError::OutOfRange),
+                TruthValuesPowerSet::try_from(bits)
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_consistent() {
+        assert!(!TruthValuesPowerSet::____.is_consistent());
+        assert!(TruthValuesPowerSet::N___.is_consistent());
+
+        // Observing `{True, Both}` contradicts a value that was `{Neither, False}`.
+        let contradiction = Unknown::NF__.refine(Unknown::__TB);
+        assert!(!contradiction.is_consistent());
+        assert!(Unknown::NF__.refine(Unknown::_F_B).is_consistent());
+
+        for a in TruthValuesPowerSet::ALL {
+            assert_eq!(a.is_consistent(), !a.is_empty());
+            for b in TruthValuesPowerSet::ALL {
+                if a.is_consistent() && b.is_consistent() {
+                    assert!(a.and(b).is_consistent());
+                    assert!(a.or(b).is_consistent());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            TruthValuesPowerSet::_FT_,
+            TruthValuesPowerSet::new(false, true, true, false)
+        );
+
+        for (bits, set) in TruthValuesPowerSet::ALL.into_iter().enumerate() {
+            let [neither, f, t, both] = [0, 1, 2, 3].map(|i| bits & (1 << i) != 0);
+            let built = TruthValuesPowerSet::new(neither, f, t, both);
+            assert_eq!(set, built);
+            assert_eq!(neither, built.contains(Belnapian::Neither));
+            assert_eq!(f, built.contains(Belnapian::False));
+            assert_eq!(t, built.contains(Belnapian::True));
+            assert_eq!(both, built.contains(Belnapian::Both));
+        }
+    }
+
+    #[test]
+    fn test_u8_conversions() {
+        for bits in 0..=15u8 {
+            let set = TruthValuesPowerSet::try_from(bits).unwrap();
+            assert_eq!(TruthValuesPowerSet::ALL[bits as usize], set);
+            assert_eq!(bits, u8::from(set));
+        }
+
+        for bits in 16..=u8::MAX {
+            assert_eq!(
+                Err(ConversionError::OutOfRange),
+                TruthValuesPowerSet::try_from(bits)
+            );
+        }
+    }
+
+    #[test]
+    fn test_subset_queries() {
+        assert!(TruthValuesPowerSet::_F__.is_subset_of(TruthValuesPowerSet::NF_B));
+        assert!(TruthValuesPowerSet::N__B.is_subset_of(TruthValuesPowerSet::N__B));
+        assert!(!TruthValuesPowerSet::_FT_.is_subset_of(TruthValuesPowerSet::NF_B));
+        assert!(!TruthValuesPowerSet::NF__.is_subset_of(TruthValuesPowerSet::__TB));
+        assert!(!TruthValuesPowerSet::__TB.is_superset_of(TruthValuesPowerSet::NF__));
+        assert!(TruthValuesPowerSet::_FTB.is_superset_of(TruthValuesPowerSet::_F_B));
+
+        for set in TruthValuesPowerSet::ALL {
+            assert!(TruthValuesPowerSet::NFTB.is_superset_of(set));
+            assert!(TruthValuesPowerSet::____.is_subset_of(set));
+
+            for other in TruthValuesPowerSet::ALL {
+                assert_eq!(set.is_subset_of(other), other.is_superset_of(set));
+                assert_eq!(set.is_subset_of(other), set.union(other) == other);
+            }
+        }
+    }
+
+    #[test]
+    fn test_subset_order() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            Some(Ordering::Less),
+            TruthValuesPowerSet::_F__.partial_cmp(&TruthValuesPowerSet::_FT_)
+        );
+        assert_eq!(
+            Some(Ordering::Greater),
+            TruthValuesPowerSet::NF_B.partial_cmp(&TruthValuesPowerSet::N__B)
+        );
+        assert_eq!(
+            None,
+            TruthValuesPowerSet::NF__.partial_cmp(&TruthValuesPowerSet::__TB)
+        );
+        assert_eq!(
+            None,
+            TruthValuesPowerSet::_F__.partial_cmp(&TruthValuesPowerSet::__T_)
+        );
+
+        for set in TruthValuesPowerSet::ALL {
+            assert!(TruthValuesPowerSet::____ <= set);
+            assert!(set <= TruthValuesPowerSet::NFTB);
+            assert_eq!(Some(Ordering::Equal), set.partial_cmp(&set));
+        }
+    }
+
+    #[test]
+    fn test_bits() {
+        assert_eq!(0b0000, TruthValuesPowerSet::____.to_bits());
+        assert_eq!(0b1001, TruthValuesPowerSet::N__B.to_bits());
+        assert_eq!(
+            Some(TruthValuesPowerSet::NFTB),
+            TruthValuesPowerSet::from_bits(15)
+        );
+        assert_eq!(None, TruthValuesPowerSet::from_bits(16));
+
+        for a in TruthValuesPowerSet::ALL {
+            assert_eq!(Some(a), TruthValuesPowerSet::from_bits(a.to_bits()));
+            assert_eq!(a.complement().to_bits(), !a.to_bits() & 0b1111);
+
+            for b in TruthValuesPowerSet::ALL {
+                assert_eq!(a.union(b).to_bits(), a.to_bits() | b.to_bits());
+                assert_eq!(a.intersection(b).to_bits(), a.to_bits() & b.to_bits());
+            }
+        }
+    }
+
+    #[test]
+    fn test_debug_bits() {
+        assert_eq!([false; 4], TruthValuesPowerSet::____.debug_bits());
+        assert_eq!(
+            [false, true, true, false],
+            TruthValuesPowerSet::_FT_.debug_bits()
+        );
+        assert_eq!(
+            [true, false, false, true],
+            TruthValuesPowerSet::N__B.debug_bits()
+        );
+
+        assert_eq!("N=0 F=0 T=0 B=0", TruthValuesPowerSet::____.debug_str());
+        assert_eq!("N=0 F=1 T=1 B=0", TruthValuesPowerSet::_FT_.debug_str());
+        assert_eq!("N=1 F=1 T=0 B=1", TruthValuesPowerSet::NF_B.debug_str());
+
+        assert_eq!([true, false, true, true], Unknown::N_TB.debug_bits());
+        assert_eq!("N=1 F=0 T=1 B=1", Unknown::N_TB.debug_str());
+
+        assert_eq!(
+            [false, false, true, false],
+            EBelnapian::Known(Belnapian::True).debug_bits()
+        );
+        assert_eq!(
+            "N=1 F=1 T=1 B=1",
+            EBelnapian::Unknown(Unknown::NFTB).debug_str()
+        );
+    }
+
+    #[test]
+    fn test_canonical() {
+        assert_eq!(CanonicalValue::Empty, TruthValuesPowerSet::____.canonical());
+        assert_eq!(
+            CanonicalValue::Known(Belnapian::Both),
+            TruthValuesPowerSet::___B.canonical()
+        );
+        assert_eq!(
+            CanonicalValue::Unknown(Unknown::NF_B),
+            TruthValuesPowerSet::NF_B.canonical()
+        );
+    }
+
+    #[test]
+    fn test_belnapian_conversions() {
+        assert_eq!(Ok(Belnapian::Neither), TruthValuesPowerSet::N___.try_into());
+        assert_eq!(Ok(Belnapian::False), TruthValuesPowerSet::_F__.try_into());
+        assert_eq!(Ok(Belnapian::True), TruthValuesPowerSet::__T_.try_into());
+        assert_eq!(Ok(Belnapian::Both), TruthValuesPowerSet::___B.try_into());
+
+        assert_eq!(
+            Err(ConversionError::EmptySet),
+            Belnapian::try_from(TruthValuesPowerSet::____)
+        );
+        assert_eq!(
+            Err(ConversionError::NotSingleton),
+            Belnapian::try_from(TruthValuesPowerSet::_FT_)
+        );
+        assert_eq!(
+            Err(ConversionError::NotSingleton),
+            Belnapian::try_from(TruthValuesPowerSet::NFTB)
+        );
+    }
+
+    #[test]
+    fn test_ternary_truth_conversions() {
+        assert_eq!(
+            Ok(TernaryTruth::False),
+            TruthValuesPowerSet::_F__.try_into()
+        );
+        assert_eq!(Ok(TernaryTruth::True), TruthValuesPowerSet::__T_.try_into());
+        assert_eq!(
+            Ok(TernaryTruth::Unknown),
+            TruthValuesPowerSet::_FT_.try_into()
+        );
+
+        assert_eq!(
+            Err(ConversionError::EmptySet),
+            TernaryTruth::try_from(TruthValuesPowerSet::____)
+        );
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            TernaryTruth::try_from(TruthValuesPowerSet::___B)
+        );
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            TernaryTruth::try_from(TruthValuesPowerSet::NF__)
+        );
+        assert_eq!(
+            Err(ConversionError::NotClassical),
+            TernaryTruth::try_from(TruthValuesPowerSet::_FTB)
+        );
+    }
+}
+
+#[cfg(test)]
+mod unknown_test {
+    use belnapian::*;
+
+    #[test]
+    fn test_packing() {
+        assert_eq!(1, std::mem::size_of::<Unknown>());
+    }
+
+    #[test]
+    fn test_refine() {
+        assert_eq!(
+            TruthValuesPowerSet::_F__,
+            Unknown::NF__.refine(Unknown::_FT_)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::_FT_,
+            Unknown::NFT_.refine(Unknown::_FTB)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::N_T_,
+            Unknown::NFTB.refine(Unknown::N_T_)
+        );
+        assert_eq!(
+            TruthValuesPowerSet::____,
+            Unknown::NF__.refine(Unknown::__TB)
+        );
+
+        for a in Unknown::ALL {
+            assert_eq!(TruthValuesPowerSet::from(a), a.refine(a));
+            for b in Unknown::ALL {
+                assert_eq!(a.refine(b), b.refine(a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_widen() {
+        assert_eq!(Unknown::NFTB, Unknown::NF__.widen(Unknown::__TB));
+        assert_eq!(Unknown::NFT_, Unknown::NF__.widen(Unknown::_FT_));
+        assert_eq!(Unknown::_FTB, Unknown::_F_B.widen(Unknown::__TB));
+        assert_eq!(Unknown::NF_B, Unknown::N__B.widen(Unknown::NF__));
+
+        for a in Unknown::ALL {
+            assert_eq!(a, a.widen(a));
+            for b in Unknown::ALL {
+                assert_eq!(a.widen(b), b.widen(a));
+            }
+        }
+    }
+
+    #[test]
+    fn test_masked_ops() {
+        for a in Unknown::ALL {
+            for b in Unknown::ALL {
+                assert_eq!(a.and(b), a.and_masked(b), "{a:?} and {b:?}");
+                assert_eq!(a.or(b), a.or_masked(b), "{a:?} or {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_macro() {
+        assert_eq!(Unknown::_FT_, unknown!(False, True));
+
+        assert_eq!(Unknown::NF__, unknown!(Neither, False));
+        assert_eq!(Unknown::N_T_, unknown!(Neither, True));
+        assert_eq!(Unknown::NFT_, unknown!(Neither, False, True));
+        assert_eq!(Unknown::N__B, unknown!(Neither, Both));
+        assert_eq!(Unknown::_F_B, unknown!(False, Both));
+        assert_eq!(Unknown::NF_B, unknown!(Neither, False, Both));
+        assert_eq!(Unknown::__TB, unknown!(True, Both));
+        assert_eq!(Unknown::N_TB, unknown!(Neither, True, Both));
+        assert_eq!(Unknown::_FTB, unknown!(False, True, Both));
+        assert_eq!(Unknown::NFTB, unknown!(Neither, False, True, Both));
+    }
+
+    #[test]
+    fn test_xor() {
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            Unknown::_FT_.xor(Unknown::_FT_)
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::N_T_),
+            Unknown::NF__.xor(Unknown::N_T_)
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::NF_B),
+            Unknown::N__B.xor(Unknown::N__B)
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::N__B),
+            Unknown::N__B.xor(Unknown::_FT_)
+        );
+    }
+}
+
+#[cfg(test)]
+mod ebelnapian_tests {
+    use super::folds_tests::poisoned_tail;
+    use belnapian::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(
+            Some(Belnapian::Both),
+            EBelnapian::Known(Belnapian::Both).resolve()
+        );
+        assert_eq!(None, EBelnapian::Unknown(Unknown::_FT_).resolve());
+        assert!(EBelnapian::Known(Belnapian::Neither).is_known());
+        assert!(!EBelnapian::Unknown(Unknown::NFTB).is_known());
+
+        for value in EBelnapian::ALL {
+            assert_eq!(value.is_known(), !value.is_unknown());
+            assert_eq!(value.is_known(), value.resolve().is_some());
+        }
+
+        // Refining an unknown value until it becomes certain.
+        let refined = Unknown::NF__.refine(Unknown::_FT_);
+        assert_eq!(
+            Some(Belnapian::False),
+            EBelnapian::try_from(refined).unwrap().resolve()
+        );
+    }
+
+    #[test]
+    fn test_knowledge_meet_join() {
+        for a in EBelnapian::ALL {
+            for b in EBelnapian::ALL {
+                assert_eq!(a.annihilation(b), a.knowledge_meet(b));
+                assert_eq!(a.superposition(b), a.knowledge_join(b));
+                assert_eq!(a.knowledge_meet(b), b.knowledge_meet(a));
+                assert_eq!(a.knowledge_join(b), b.knowledge_join(a));
+            }
+
+            // Idempotent on known values; on unknown values the result still
+            // admits every member of the operand.
+            let set = TruthValuesPowerSet::from(a);
+            for op in [EBelnapian::knowledge_meet, EBelnapian::knowledge_join] {
+                match a {
+                    EBelnapian::Known(_) => assert_eq!(a, op(a, a)),
+                    EBelnapian::Unknown(_) => {
+                        assert_eq!(set, set.intersection(op(a, a).into()))
+                    }
+                }
+            }
+        }
+
+        for a in Unknown::ALL {
+            for b in Unknown::ALL {
+                assert_eq!(a.knowledge_meet(b), b.knowledge_meet(a));
+                assert_eq!(a.knowledge_join(b), b.knowledge_join(a));
+            }
+        }
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::NFT_),
+            Unknown::_FT_.knowledge_meet(Unknown::_FT_)
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FTB),
+            Unknown::_FT_.knowledge_join(Unknown::_FT_)
+        );
+    }
+
+    #[test]
+    fn test_xor_lifted() {
+        fn generic_xor<T: XorOp>(a: T, b: T) -> T {
+            a.xor(b)
+        }
+
+        let lifted = lift_binary(Belnapian::xor);
+        for a in EBelnapian::all() {
+            for b in EBelnapian::all() {
+                assert_eq!(lifted(a, b), a.xor(b));
+                assert_eq!(a.xor(b), generic_xor(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lift_binary() {
+        let and = lift_binary(Belnapian::and);
+        let or = lift_binary(Belnapian::or);
+        for a in EBelnapian::all() {
+            for b in EBelnapian::all() {
+                assert_eq!(a.and(b), and(a, b));
+                assert_eq!(a.or(b), or(a, b));
+            }
+        }
+
+        let implies = lift_binary(Belnapian::implies);
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            implies(
+                EBelnapian::Known(Belnapian::False),
+                EBelnapian::Unknown(Unknown::NFTB)
+            )
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            implies(
+                EBelnapian::Unknown(Unknown::_FT_),
+                EBelnapian::Known(Belnapian::False)
+            )
+        );
+    }
+
+    #[test]
+    fn test_structural_eq() {
+        assert_ne!(
+            EBelnapian::Known(Belnapian::False),
+            EBelnapian::Known(Belnapian::True)
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Unknown(Unknown::_FT_)
+        );
+        assert_ne!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Unknown(Unknown::NFT_)
+        );
+
+        let values = EBelnapian::all();
+        for (i, a) in values.iter().enumerate() {
+            for (j, b) in values.iter().enumerate() {
+                assert_eq!(i == j, a == b, "{a:?} == {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_packing() {
+        // TODO: find a clean way to pack it into 1 byte
+        assert_eq!(2, std::mem::size_of::<EBelnapian>());
+    }
+
+    #[test]
+    fn test_xor() {
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::Known(Belnapian::True).xor(EBelnapian::Known(Belnapian::False))
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Unknown(Unknown::_FT_).xor(EBelnapian::Known(Belnapian::True))
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::NF__),
+            EBelnapian::Known(Belnapian::Neither).xor(EBelnapian::Unknown(Unknown::_FTB))
+        );
+    }
+
+    #[test]
+    fn test_implies() {
+        for a in EBelnapian::ALL {
+            for b in EBelnapian::ALL {
+                assert_eq!(a.not().or(b), a.implies(b), "{a:?} → {b:?}");
+            }
+        }
+
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::__TB),
+            Unknown::_FT_.implies(Unknown::__TB)
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::Known(Belnapian::False).implies(EBelnapian::Unknown(Unknown::NFTB))
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::__TB),
+            EBelnapian::Unknown(Unknown::_F_B).implies(EBelnapian::Known(Belnapian::Both))
+        );
+    }
+
+    #[test]
+    fn test_implication_operator() {
+        assert_eq!(
+            EBelnapian::Known(Belnapian::False),
+            EBelnapian::Known(Belnapian::True) >> EBelnapian::Known(Belnapian::False)
+        );
+
+        let values = [
+            EBelnapian::Known(Belnapian::Neither),
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Unknown(Unknown::N__B),
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(a.implies(b), a >> b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_and_all() {
+        assert_eq!(EBelnapian::Known(Belnapian::True), EBelnapian::and_all([]));
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_F_B),
+            EBelnapian::and_all([
+                EBelnapian::Known(Belnapian::True),
+                EBelnapian::Known(Belnapian::Both),
+                EBelnapian::Unknown(Unknown::NFTB),
+            ])
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::False),
+            EBelnapian::and_all(
+                [
+                    EBelnapian::Unknown(Unknown::NF__),
+                    EBelnapian::Known(Belnapian::False),
+                ]
+                .into_iter()
+                .chain(poisoned_tail())
+            )
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::False),
+            EBelnapian::and_all(
+                [
+                    EBelnapian::Known(Belnapian::Neither),
+                    EBelnapian::Known(Belnapian::Both),
+                ]
+                .into_iter()
+                .chain(poisoned_tail())
+            )
+        );
+    }
+
+    #[test]
+    fn test_or_all() {
+        assert_eq!(EBelnapian::Known(Belnapian::False), EBelnapian::or_all([]));
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::__TB),
+            EBelnapian::or_all([
+                EBelnapian::Known(Belnapian::False),
+                EBelnapian::Known(Belnapian::Both),
+                EBelnapian::Unknown(Unknown::NFTB),
+            ])
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::or_all(
+                [
+                    EBelnapian::Unknown(Unknown::_FT_),
+                    EBelnapian::Known(Belnapian::Neither),
+                    EBelnapian::Known(Belnapian::True),
+                ]
+                .into_iter()
+                .chain(poisoned_tail())
+            )
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::or_all(
+                [
+                    EBelnapian::Known(Belnapian::Neither),
+                    EBelnapian::Known(Belnapian::Both),
+                ]
+                .into_iter()
+                .chain(poisoned_tail())
+            )
+        );
+    }
+
+    #[test]
+    fn test_uniform_distribution() {
+        assert_eq!(
+            [0.0, 0.5, 0.5, 0.0],
+            EBelnapian::Unknown(Unknown::_FT_).uniform_distribution()
+        );
+        assert_eq!(
+            [0.0, 0.0, 0.0, 1.0],
+            EBelnapian::Known(Belnapian::Both).uniform_distribution()
+        );
+        assert_eq!(
+            [0.25, 0.25, 0.25, 0.25],
+            EBelnapian::Unknown(Unknown::NFTB).uniform_distribution()
+        );
+
+        for value in EBelnapian::all() {
+            let total: f64 = value.uniform_distribution().iter().sum();
+            assert!((total - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_agreement() {
+        assert_eq!(
+            Agreement::Identical,
+            EBelnapian::Known(Belnapian::True).agreement(EBelnapian::Known(Belnapian::True))
+        );
+        assert_eq!(
+            Agreement::Identical,
+            EBelnapian::Unknown(Unknown::NF_B).agreement(EBelnapian::Unknown(Unknown::NF_B))
+        );
+        assert_eq!(
+            Agreement::Overlapping,
+            EBelnapian::Unknown(Unknown::_FT_).agreement(EBelnapian::Unknown(Unknown::__TB))
+        );
+        assert_eq!(
+            Agreement::Overlapping,
+            EBelnapian::Known(Belnapian::Both).agreement(EBelnapian::Unknown(Unknown::__TB))
+        );
+        assert_eq!(
+            Agreement::Disjoint,
+            EBelnapian::Known(Belnapian::False).agreement(EBelnapian::Known(Belnapian::True))
+        );
+        assert_eq!(
+            Agreement::Disjoint,
+            EBelnapian::Unknown(Unknown::NF__).agreement(EBelnapian::Unknown(Unknown::__TB))
+        );
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        assert_eq!(
+            0x6E73_E372_E233_8ACA,
+            EBelnapian::Known(Belnapian::True).stable_hash()
+        );
+        assert_eq!(
+            0xBD64_A5D9_ADEF_E000,
+            EBelnapian::Unknown(Unknown::_FT_).stable_hash()
+        );
+        assert_ne!(
+            EBelnapian::Known(Belnapian::False).stable_hash(),
+            EBelnapian::Known(Belnapian::True).stable_hash()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_partition_by() {
+        let partition = EBelnapian::partition_by(&EBelnapian::all(), |v| v.is_unknown());
+
+        assert_eq!(2, partition.len());
+        assert_eq!(4, partition[&false].len());
+        assert_eq!(11, partition[&true].len());
+        assert!(partition[&false].contains(&EBelnapian::Known(Belnapian::Both)));
+        assert!(partition[&true].contains(&EBelnapian::Unknown(Unknown::_FT_)));
+
+        let partition = EBelnapian::partition_by(&EBelnapian::all(), |v| match v {
+            EBelnapian::Known(value) => value == Belnapian::True,
+            EBelnapian::Unknown(value) => value.could_be_true(),
+        });
+        assert_eq!(8, partition[&true].len());
+        assert_eq!(7, partition[&false].len());
+    }
+
+    #[test]
+    fn test_into_powerset() {
+        assert_eq!(
+            TruthValuesPowerSet::__T_,
+            TruthValuesPowerSet::from(EBelnapian::Known(Belnapian::True))
+        );
+        assert_eq!(
+            TruthValuesPowerSet::NF_B,
+            TruthValuesPowerSet::from(EBelnapian::Unknown(Unknown::NF_B))
+        );
+
+        for value in EBelnapian::all() {
+            let set = TruthValuesPowerSet::from(value);
+            assert_eq!(1 + value.is_unknown() as usize, set.len().min(2));
+            if let EBelnapian::Unknown(unknown) = value {
+                assert_eq!(TruthValuesPowerSet::from(unknown), set);
+            }
+        }
+    }
+
+    #[test]
+    fn test_powerset_round_trip() {
+        for value in EBelnapian::ALL {
+            assert_eq!(Ok(value), TruthValuesPowerSet::from(value).try_into());
+        }
+        assert_eq!(
+            Err(ConversionError::EmptySet),
+            EBelnapian::try_from(TruthValuesPowerSet::____)
+        );
+        assert_eq!(
+            Ok(EBelnapian::Unknown(Unknown::N_T_)),
+            EBelnapian::try_from(TruthValuesPowerSet::N_T_)
+        );
+    }
+
+    #[test]
+    fn test_from_ternary() {
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::from(TernaryTruth::Unknown)
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::NFTB),
+            EBelnapian::from_ternary_wide(TernaryTruth::Unknown)
+        );
+
+        for value in [TernaryTruth::False, TernaryTruth::True] {
+            assert_eq!(
+                EBelnapian::from(value),
+                EBelnapian::from_ternary_wide(value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_faithful_ternary() {
+        for value in [
+            EBelnapian::Known(Belnapian::False),
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::Unknown(Unknown::_FT_),
+        ] {
+            assert!(value.is_faithful_ternary());
+
+            let ternary: TernaryTruth = value.try_into().unwrap();
+            assert_eq!(value, ternary.into());
+        }
+
+        for value in [
+            EBelnapian::Known(Belnapian::Neither),
+            EBelnapian::Known(Belnapian::Both),
+            EBelnapian::Unknown(Unknown::NF__),
+            EBelnapian::Unknown(Unknown::NFTB),
+        ] {
+            assert!(!value.is_faithful_ternary());
+            assert!(TernaryTruth::try_from(value).is_err());
+        }
+    }
+
+    #[test]
+    fn test_generalize() {
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Known(Belnapian::True).generalize(EBelnapian::Known(Belnapian::False))
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::NFTB),
+            EBelnapian::Unknown(Unknown::NF__).generalize(EBelnapian::Unknown(Unknown::__TB))
+        );
+        assert_eq!(
+            EBelnapian::Unknown(Unknown::N_TB),
+            EBelnapian::Known(Belnapian::Both).generalize(EBelnapian::Unknown(Unknown::N_T_))
+        );
+
+        for value in [
+            EBelnapian::Known(Belnapian::Neither),
+            EBelnapian::Known(Belnapian::Both),
+            EBelnapian::Unknown(Unknown::_F_B),
+            EBelnapian::Unknown(Unknown::NFTB),
+        ] {
+            assert_eq!(value, value.generalize(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod logic_operand_tests {
+    use belnapian::*;
+
+    fn exclusive_implication<T: LogicOperand>(a: T, b: T) -> T {
+        a.xor(b).and(a.implies(b)).or(!a)
+    }
+
+    fn double_negation<T: NotOp + PartialEq + std::fmt::Debug>(values: &[T]) {
+        for value in values {
+            assert_eq!(*value, NotOp::not(NotOp::not(*value)));
+        }
+    }
+
+    fn self_implication<T: LogicOperand + Classical + PartialEq + std::fmt::Debug>(values: &[T]) {
+        for value in values {
+            assert_eq!((!*value).or(*value), ImpliesOp::implies(*value, *value));
+        }
+        assert_eq!(T::TRUE, ImpliesOp::implies(T::TRUE, T::TRUE));
+        assert_eq!(T::TRUE, ImpliesOp::implies(T::FALSE, T::FALSE));
+    }
+
+    #[test]
+    fn test_implies_op() {
+        self_implication(&Belnapian::ALL);
+        self_implication(&TernaryTruth::ALL);
+
+        for value in EBelnapian::ALL {
+            assert_eq!((!value).or(value), ImpliesOp::implies(value, value));
+        }
+    }
+
+    #[test]
+    fn test_not_op() {
+        double_negation(&Belnapian::ALL);
+        double_negation(&TernaryTruth::ALL);
+        double_negation(&Unknown::ALL);
+        double_negation(&EBelnapian::ALL);
+
+        assert_eq!(Belnapian::True, NotOp::not(Belnapian::False));
+        assert_eq!(Unknown::N__B, NotOp::not(Unknown::N__B));
+        assert_eq!(Unknown::_FTB, NotOp::not(Unknown::_FTB));
+    }
+
+    fn de_morgan<T: LogicOperand + PartialEq + std::fmt::Debug>(values: &[T]) {
+        for a in values {
+            for b in values {
+                assert_eq!(!a.and(*b), (!*a).or(!*b));
+                assert_eq!(!a.or(*b), (!*a).and(!*b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_morgan() {
+        de_morgan(&Belnapian::ALL);
+        de_morgan(&TernaryTruth::ALL);
+        de_morgan(&EBelnapian::ALL);
+    }
+
+    #[test]
+    fn test_scans() {
+        use Belnapian::{Both, False, Neither, True};
+
+        let values = [True, Both, Neither, False, True];
+        assert_eq!(
+            vec![True, Both, False, False, False],
+            values.iter().copied().and_scan().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![True, True, True, True, True],
+            values.iter().copied().or_scan().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Neither, Neither, True],
+            vec![Neither, False, True]
+                .into_iter()
+                .or_scan()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(None, Vec::<Belnapian>::new().into_iter().and_scan().next());
+        assert_eq!(5, values.iter().copied().and_scan().len());
+
+        assert_eq!(
+            vec![TernaryTruth::Unknown, TernaryTruth::False],
+            [TernaryTruth::Unknown, TernaryTruth::False]
+                .into_iter()
+                .and_scan()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_generic_operands() {
+        assert_eq!(
+            Belnapian::True,
+            exclusive_implication(Belnapian::False, Belnapian::True)
+        );
+        assert_eq!(
+            TernaryTruth::False,
+            exclusive_implication(TernaryTruth::True, TernaryTruth::False)
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            exclusive_implication(
+                EBelnapian::Known(Belnapian::False),
+                EBelnapian::Known(Belnapian::True)
+            )
+        );
+    }
+
+    fn conjunction<T: Classical + LogicOperand>(values: &[T]) -> T {
+        values.iter().fold(T::TRUE, |acc, value| acc.and(*value))
+    }
+
+    fn disjunction<T: Classical + LogicOperand>(values: &[T]) -> T {
+        values.iter().fold(T::FALSE, |acc, value| acc.or(*value))
+    }
+
+    #[test]
+    fn test_classical() {
+        assert_eq!(Belnapian::True, conjunction::<Belnapian>(&[]));
+        assert_eq!(Belnapian::False, disjunction::<Belnapian>(&[]));
+        assert_eq!(
+            Belnapian::Both,
+            conjunction(&[Belnapian::True, Belnapian::Both])
+        );
+
+        assert_eq!(TernaryTruth::True, conjunction::<TernaryTruth>(&[]));
+        assert_eq!(TernaryTruth::False, disjunction::<TernaryTruth>(&[]));
+        assert_eq!(
+            TernaryTruth::Unknown,
+            disjunction(&[TernaryTruth::False, TernaryTruth::Unknown])
+        );
+    }
+
+    #[test]
+    fn test_nand_nor() {
+        let values = [
+            EBelnapian::Known(Belnapian::Neither),
+            EBelnapian::Known(Belnapian::Both),
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Unknown(Unknown::NF_B),
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(!a.and(b), NandOp::nand(a, b));
+                assert_eq!(!a.or(b), NorOp::nor(a, b));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod inference_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_kleene_fixpoint() {
+        let f = |x: Belnapian| x.or(Belnapian::True);
+        assert_eq!(Belnapian::True, kleene_fixpoint(f, Belnapian::Neither));
+        assert_eq!(Belnapian::True, kleene_fixpoint(f, Belnapian::True));
+        assert_eq!(Belnapian::Both, kleene_fixpoint(f, Belnapian::False));
+        assert_eq!(Belnapian::Both, kleene_fixpoint(f, Belnapian::Both));
+
+        for start in Belnapian::ALL {
+            assert_eq!(start, kleene_fixpoint(|x| x, start));
+        }
+    }
+
+    #[test]
+    fn test_superposition_scan() {
+        let steps: Vec<EBelnapian> = superposition_scan([
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::Known(Belnapian::False),
+        ])
+        .collect();
+        assert_eq!(
+            vec![
+                EBelnapian::Known(Belnapian::True),
+                EBelnapian::Known(Belnapian::Both)
+            ],
+            steps
+        );
+
+        assert_eq!(0, superposition_scan([]).count());
+        assert_eq!(
+            vec![EBelnapian::Unknown(Unknown::_FT_)],
+            superposition_scan([EBelnapian::Unknown(Unknown::_FT_)]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(Belnapian::False, resolve(&[], &[]));
+        assert_eq!(
+            Belnapian::False,
+            resolve(&[Belnapian::False], &[Belnapian::False, Belnapian::False])
+        );
+        assert_eq!(
+            Belnapian::True,
+            resolve(&[Belnapian::False, Belnapian::True], &[Belnapian::False])
+        );
+        assert_eq!(
+            Belnapian::Neither,
+            resolve(&[Belnapian::Neither], &[Belnapian::Neither])
+        );
+        assert_eq!(
+            Belnapian::Both,
+            resolve(&[Belnapian::False, Belnapian::Both], &[Belnapian::False])
+        );
+        assert_eq!(
+            Belnapian::True,
+            resolve(&[Belnapian::Both], &[Belnapian::Neither])
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod formula_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_builder() {
+        let formula = Formula::var(0)
+            .and(Formula::var(1).not())
+            .implies(Formula::constant(Belnapian::Both));
+
+        assert_eq!(
+            Formula::Implies(
+                Box::new(Formula::And(
+                    Box::new(Formula::Var(0)),
+                    Box::new(Formula::Not(Box::new(Formula::Var(1))))
+                )),
+                Box::new(Formula::Const(Belnapian::Both))
+            ),
+            formula
+        );
+
+        assert_eq!(
+            Belnapian::Both,
+            formula.eval(&[Belnapian::True, Belnapian::False])
+        );
+        assert_eq!(
+            Belnapian::True,
+            formula.eval(&[Belnapian::False, Belnapian::Neither])
+        );
+        assert_eq!(
+            Belnapian::True,
+            formula.eval(&[Belnapian::Neither, Belnapian::Neither])
+        );
+    }
+
+    #[test]
+    fn test_eval_or() {
+        assert_eq!(
+            TernaryTruth::Unknown,
+            Formula::var(0)
+                .or(Formula::var(1))
+                .eval(&[TernaryTruth::False, TernaryTruth::Unknown])
+        );
+        assert_eq!(
+            EBelnapian::Known(Belnapian::True),
+            Formula::var(0).or(Formula::var(1)).eval(&[
+                EBelnapian::Unknown(Unknown::NFTB),
+                EBelnapian::Known(Belnapian::True)
+            ])
+        );
+    }
+
+    fn check_de_morgan<V: LogicOperand + PartialEq + std::fmt::Debug>(values: &[V]) {
+        let not_and = Formula::var(0).and(Formula::var(1)).not();
+        let or_not = Formula::var(0).not().or(Formula::var(1).not());
+        let not_or = Formula::var(0).or(Formula::var(1)).not();
+        let and_not = Formula::var(0).not().and(Formula::var(1).not());
+
+        for a in values {
+            for b in values {
+                let assignment = [*a, *b];
+                assert_eq!(not_and.eval(&assignment), or_not.eval(&assignment));
+                assert_eq!(not_or.eval(&assignment), and_not.eval(&assignment));
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_morgan() {
+        check_de_morgan(&Belnapian::ALL);
+        check_de_morgan(&TernaryTruth::ALL);
+    }
+}
+
+// These assertions are evaluated at compile time: a broken table fails the
+// build of the test suite instead of a single test.
+#[cfg(test)]
+mod const_tests {
+    use belnapian::{Belnapian, TernaryTruth};
+
+    const _: () = assert!(Belnapian::True
+        .and(Belnapian::Both)
+        .eq_const(Belnapian::Both));
+    const _: () = assert!(Belnapian::Neither
+        .and(Belnapian::Both)
+        .eq_const(Belnapian::False));
+    const _: () = assert!(Belnapian::Neither
+        .or(Belnapian::Both)
+        .eq_const(Belnapian::True));
+    const _: () = assert!(Belnapian::True
+        .xor(Belnapian::True)
+        .eq_const(Belnapian::False));
+    const _: () = assert!(Belnapian::Both.not().eq_const(Belnapian::Both));
+    const _: () = assert!(Belnapian::False
+        .superposition(Belnapian::True)
+        .eq_const(Belnapian::Both));
+    const _: () = assert!(Belnapian::False
+        .annihilation(Belnapian::True)
+        .eq_const(Belnapian::Neither));
+    const _: () = assert!(!Belnapian::Neither.eq_const(Belnapian::Both));
+
+    const TRUE_AND_BOTH: Belnapian = Belnapian::True.and(Belnapian::Both);
+    const _: () = assert!(TRUE_AND_BOTH.eq_const(Belnapian::Both));
+
+    const _: () = assert!(TernaryTruth::True
+        .and(TernaryTruth::Unknown)
+        .eq_const(TernaryTruth::Unknown));
+    const _: () = assert!(TernaryTruth::Unknown
+        .or(TernaryTruth::True)
+        .eq_const(TernaryTruth::True));
+    const _: () = assert!(TernaryTruth::True
+        .xor(TernaryTruth::False)
+        .eq_const(TernaryTruth::True));
+    const _: () = assert!(TernaryTruth::Unknown.not().eq_const(TernaryTruth::Unknown));
+    const _: () = assert!(TernaryTruth::False
+        .implies(TernaryTruth::Unknown)
+        .eq_const(TernaryTruth::True));
+    const _: () = assert!(!TernaryTruth::False.eq_const(TernaryTruth::Unknown));
+
+    #[test]
+    fn test_eq_const() {
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a == b, a.eq_const(b));
+            }
+        }
+        for a in TernaryTruth::ALL {
+            for b in TernaryTruth::ALL {
+                assert_eq!(a == b, a.eq_const(b));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod folds_tests {
+    use belnapian::*;
+
+    // An iterator that panics as soon as it's polled, to check that the
+    // short-circuiting folds never read past the value that stops them.
+    pub(super) fn poisoned_tail<T>() -> impl Iterator<Item = T> {
+        std::iter::from_fn(|| panic!("the tail must not be consumed"))
+    }
+
+    #[test]
+    fn test_fold_with_absorbing() {
+        let values = [Belnapian::True, Belnapian::False];
+        let tail = values.into_iter().chain(poisoned_tail());
+        assert_eq!(
+            Belnapian::False,
+            fold_with_absorbing(
+                tail,
+                Belnapian::and,
+                Belnapian::True,
+                Some(Belnapian::False)
+            )
+        );
+
+        assert_eq!(
+            Belnapian::True,
+            fold_with_absorbing([], Belnapian::and, Belnapian::True, Some(Belnapian::False))
+        );
+        assert_eq!(
+            Belnapian::False,
+            fold_with_absorbing(
+                poisoned_tail(),
+                Belnapian::and,
+                Belnapian::False,
+                Some(Belnapian::False)
+            )
+        );
+        assert_eq!(
+            Belnapian::Neither,
+            fold_with_absorbing(
+                [Belnapian::True, Belnapian::Neither, Belnapian::True],
+                Belnapian::and,
+                Belnapian::True,
+                None
+            )
+        );
+        assert_eq!(
+            Belnapian::Both,
+            fold_with_absorbing(
+                [Belnapian::Neither, Belnapian::True, Belnapian::False],
+                Belnapian::superposition,
+                Belnapian::Neither,
+                Some(Belnapian::Both)
+            )
+        );
+    }
+
+    #[test]
+    fn test_reduce() {
+        use Belnapian::{Both, False, Neither, True};
+
+        assert_eq!(True, Belnapian::reduce_and([]));
+        assert_eq!(False, Belnapian::reduce_or([]));
+        assert_eq!(Neither, Belnapian::reduce_superposition([]));
+        assert_eq!(Both, Belnapian::reduce_annihilation([]));
+
+        assert_eq!(Both, Belnapian::reduce_and([True, Both, True]));
+        assert_eq!(False, Belnapian::reduce_and([Neither, Both]));
+        assert_eq!(Neither, Belnapian::reduce_or([False, Neither]));
+        assert_eq!(True, Belnapian::reduce_or([Neither, Both, False]));
+        assert_eq!(
+            Both,
+            Belnapian::reduce_superposition([Neither, True, False])
+        );
+        assert_eq!(True, Belnapian::reduce_superposition([Neither, True]));
+        assert_eq!(Neither, Belnapian::reduce_annihilation([Both, True, False]));
+        assert_eq!(False, Belnapian::reduce_annihilation([Both, False]));
+
+        // Each reduction stops right at its absorbing value.
+        let poisoned = |values: [Belnapian; 2]| values.into_iter().chain(poisoned_tail());
+        assert_eq!(False, Belnapian::reduce_and(poisoned([Both, False])));
+        assert_eq!(True, Belnapian::reduce_or(poisoned([Neither, True])));
+        assert_eq!(
+            Both,
+            Belnapian::reduce_superposition(poisoned([True, False]))
+        );
+        assert_eq!(
+            Neither,
+            Belnapian::reduce_annihilation(poisoned([True, False]))
+        );
+
+        for value in Belnapian::ALL {
+            assert_eq!(value, Belnapian::reduce_and([value]));
+            assert_eq!(value, Belnapian::reduce_or([value]));
+            assert_eq!(value, Belnapian::reduce_superposition([value]));
+            assert_eq!(value, Belnapian::reduce_annihilation([value]));
+        }
+    }
+}
+
+#[cfg(test)]
+mod unary_functions_tests {
+    use belnapian::*;
+
+    fn conflate(value: Belnapian) -> Belnapian {
+        match value {
+            Belnapian::Neither => Belnapian::Both,
+            Belnapian::Both => Belnapian::Neither,
+            value => value,
+        }
+    }
+
+    #[test]
+    fn test_compose2() {
+        let f = compose2(Belnapian::not, conflate);
+        assert_eq!(Belnapian::Both, f(Belnapian::Neither));
+        assert_eq!(Belnapian::True, f(Belnapian::False));
+
+        let g = compose2(Belnapian::not, Belnapian::not);
+        assert_eq!(Belnapian::False, g(Belnapian::False));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_closure_contains_not() {
+        let identity = |value: Belnapian| value;
+
+        assert!(!closure_contains_not(&[]));
+        assert!(!closure_contains_not(&[
+            Box::new(conflate),
+            Box::new(identity)
+        ]));
+        assert!(closure_contains_not(&[
+            Box::new(Belnapian::not),
+            Box::new(identity)
+        ]));
+        assert!(!closure_contains_not(&[Box::new(compose2(
+            Belnapian::not,
+            conflate
+        ))]));
+        assert!(closure_contains_not(&[
+            Box::new(compose2(Belnapian::not, conflate)),
+            Box::new(conflate)
+        ]));
+    }
+}
+
+#[cfg(test)]
+mod bilattice_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_bilattice_structure() {
+        let structure = bilattice_structure();
+
+        assert_eq!(4, structure.nodes.len());
+        assert_eq!(4, structure.knowledge_edges.len());
+        assert_eq!(4, structure.truth_edges.len());
+
+        // The covering edges agree with the lattice operations
+        for (lower, upper) in structure.knowledge_edges {
+            assert_eq!(upper, lower.superposition(upper));
+            assert_eq!(lower, lower.annihilation(upper));
+        }
+        for (lower, upper) in structure.truth_edges {
+            assert_eq!(upper, lower.or(upper));
+            assert_eq!(lower, lower.and(upper));
+        }
+    }
+}
+
+#[cfg(test)]
+mod algebraic_laws_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_distributivity() {
+        assert_eq!(
+            None,
+            check_distributive(&Belnapian::ALL, Belnapian::and, Belnapian::or)
+        );
+        assert_eq!(
+            None,
+            check_distributive(&Belnapian::ALL, Belnapian::or, Belnapian::and)
+        );
+        assert_eq!(
+            None,
+            check_distributive(
+                &[
+                    TernaryTruth::False,
+                    TernaryTruth::True,
+                    TernaryTruth::Unknown
+                ],
+                TernaryTruth::and,
+                TernaryTruth::or
+            )
+        );
+
+        // The known values alone behave like `Belnapian`
+        let known = [
+            EBelnapian::Known(Belnapian::Neither),
+            EBelnapian::Known(Belnapian::False),
+            EBelnapian::Known(Belnapian::True),
+            EBelnapian::Known(Belnapian::Both),
+        ];
+        assert_eq!(
+            None,
+            check_distributive(&known, EBelnapian::and, EBelnapian::or)
+        );
+
+        // Repeating an unknown operand loses information
+        let (a, b, c) = (
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Known(Belnapian::Neither),
+            EBelnapian::Known(Belnapian::Both),
+        );
+        assert_eq!(EBelnapian::Unknown(Unknown::_FT_), a.and(b.or(c)));
+        assert_eq!(EBelnapian::Unknown(Unknown::NFTB), a.and(b).or(a.and(c)));
+        assert!(check_distributive(&EBelnapian::all(), EBelnapian::and, EBelnapian::or).is_some());
+    }
+}
+
+#[cfg(test)]
+mod memoized_op_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_apply() {
+        let and = MemoizedOp::new(Belnapian::and);
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a.and(b), and.apply(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_hot_loop() {
+        let annihilation = MemoizedOp::new(Belnapian::annihilation);
+
+        let (mut memoized, mut direct) = (Belnapian::Both, Belnapian::Both);
+        for i in 0..100_000 {
+            let (a, b) = (Belnapian::ALL[i % 4], Belnapian::ALL[(i / 4) % 4]);
+            memoized = annihilation.apply(memoized, a).superposition(b);
+            direct = direct.annihilation(a).superposition(b);
+        }
+        assert_eq!(direct, memoized);
+    }
+}
+
+#[cfg(test)]
+mod table_audit_tests {
+    use belnapian::*;
+
+    type BelnapianOp = fn(Belnapian, Belnapian) -> Belnapian;
+    type EBelnapianOp = fn(EBelnapian, EBelnapian) -> EBelnapian;
+    type UnknownOp = fn(Unknown, Unknown) -> EBelnapian;
+
+    // Independently recomputes the set-lifted result from the members of both
+    // operands, without going through any of the crate's lifting machinery.
+    fn lifted(op: BelnapianOp, a: EBelnapian, b: EBelnapian) -> EBelnapian {
+        let mut bits = 0u8;
+        for x in TruthValuesPowerSet::from(a).members() {
+            for y in TruthValuesPowerSet::from(b).members() {
+                bits |= 1 << (op(x, y) as u8);
+            }
+        }
+
+        match TruthValuesPowerSet::try_from(bits).unwrap().canonical() {
+            CanonicalValue::Known(value) => EBelnapian::Known(value),
+            CanonicalValue::Unknown(value) => EBelnapian::Unknown(value),
+            CanonicalValue::Empty => panic!("the lifted result cannot be empty"),
+        }
+    }
+
+    #[test]
+    fn test_lifted_tables() {
+        let operations: [(&str, BelnapianOp, EBelnapianOp); 7] = [
+            ("and", Belnapian::and, EBelnapian::and),
+            ("or", Belnapian::or, EBelnapian::or),
+            ("xor", Belnapian::xor, EBelnapian::xor),
+            (
+                "superposition",
+                Belnapian::superposition,
+                EBelnapian::superposition,
+            ),
+            (
+                "annihilation",
+                Belnapian::annihilation,
+                EBelnapian::annihilation,
+            ),
+            ("eq", Belnapian::eq, EBelnapian::eq),
+            ("implies", Belnapian::implies, EBelnapian::implies),
+        ];
+
+        let mut mismatches = Vec::new();
+        for (name, op, table) in operations {
+            for a in EBelnapian::all() {
+                for b in EBelnapian::all() {
+                    let (expected, actual) = (lifted(op, a, b), table(a, b));
+                    if expected != actual {
+                        mismatches.push(format!(
+                            "{name}({a:?}, {b:?}): expected {expected:?}, got {actual:?}"
+                        ));
+                    }
+                }
+            }
+        }
+
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+
+    #[test]
+    fn test_unknown_tables() {
+        let operations: [(&str, BelnapianOp, UnknownOp); 7] = [
+            ("and", Belnapian::and, Unknown::and),
+            ("or", Belnapian::or, Unknown::or),
+            ("xor", Belnapian::xor, Unknown::xor),
+            (
+                "superposition",
+                Belnapian::superposition,
+                Unknown::superposition,
+            ),
+            (
+                "annihilation",
+                Belnapian::annihilation,
+                Unknown::annihilation,
+            ),
+            ("eq", Belnapian::eq, Unknown::eq),
+            ("implies", Belnapian::implies, Unknown::implies),
+        ];
+
+        let mut mismatches = Vec::new();
+        for (name, op, table) in operations {
+            for a in EBelnapian::all() {
+                for b in EBelnapian::all() {
+                    if let (EBelnapian::Unknown(ua), EBelnapian::Unknown(ub)) = (a, b) {
+                        let (expected, actual) = (lifted(op, a, b), table(ua, ub));
+                        if expected != actual {
+                            mismatches.push(format!(
+                                "Unknown::{name}({ua:?}, {ub:?}): expected {expected:?}, got {actual:?}"
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod display_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("Neither", format!("{}", Belnapian::Neither));
+        assert_eq!("Both", format!("{}", Belnapian::Both));
+        assert_eq!("Unknown", format!("{}", TernaryTruth::Unknown));
+        assert_eq!("False", format!("{}", TernaryTruth::False));
+
+        assert_eq!("{False,True}", format!("{}", Unknown::_FT_));
+        assert_eq!("{Neither,False}", format!("{}", Unknown::NF__));
+        assert_eq!("{Neither,False,True,Both}", format!("{}", Unknown::NFTB));
+
+        assert_eq!("True", format!("{}", EBelnapian::Known(Belnapian::True)));
+        assert_eq!(
+            "Unknown{Neither,False}",
+            format!("{}", EBelnapian::Unknown(Unknown::NF__))
+        );
+
+        assert_eq!("NF__", format!("{:?}", Unknown::NF__));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Ok(Belnapian::Neither), "Neither".parse());
+        assert_eq!(Ok(Belnapian::Both), "Both".parse());
+        assert_eq!(Ok(Belnapian::False), "false".parse());
+        assert_eq!(Ok(Belnapian::True), "TRUE".parse());
+        assert_eq!(
+            Err(ConversionError::UnknownLabel),
+            "Unknown".parse::<Belnapian>()
+        );
+        assert_eq!(Err(ConversionError::UnknownLabel), "".parse::<Belnapian>());
+
+        assert_eq!(Ok(TernaryTruth::Unknown), "Unknown".parse());
+        assert_eq!(Ok(TernaryTruth::False), "fAlSe".parse());
+        assert_eq!(
+            Err(ConversionError::UnknownLabel),
+            "Both".parse::<TernaryTruth>()
+        );
+        assert_eq!(
+            Err(ConversionError::UnknownLabel),
+            " True".parse::<TernaryTruth>()
+        );
+
+        for value in Belnapian::ALL {
+            assert_eq!(Ok(value), value.to_string().parse());
+        }
+    }
+
+    #[test]
+    fn test_tags() {
+        assert_eq!("False", Belnapian::False.to_tag());
+        assert_eq!("Unknown", TernaryTruth::Unknown.to_tag());
+        assert_eq!("_FT_", Unknown::_FT_.to_tag());
+        assert_eq!("____", TruthValuesPowerSet::____.to_tag());
+        assert_eq!("N___", TruthValuesPowerSet::N___.to_tag());
+        assert_eq!("False", EBelnapian::Known(Belnapian::False).to_tag());
+        assert_eq!("Unknown(_FT_)", EBelnapian::Unknown(Unknown::_FT_).to_tag());
+
+        for value in Belnapian::ALL {
+            assert_eq!(Ok(value), Belnapian::from_tag(value.to_tag()));
+        }
+        for value in TernaryTruth::ALL {
+            assert_eq!(Ok(value), TernaryTruth::from_tag(value.to_tag()));
+        }
+        for value in Unknown::ALL {
+            assert_eq!(Ok(value), Unknown::from_tag(value.to_tag()));
+        }
+        for value in TruthValuesPowerSet::ALL {
+            assert_eq!(Ok(value), TruthValuesPowerSet::from_tag(value.to_tag()));
+        }
+        for value in EBelnapian::ALL {
+            assert_eq!(Ok(value), EBelnapian::from_tag(value.to_tag()));
+        }
+
+        for tag in ["", "false", "Unknown", "_FT_", " True"] {
+            assert_eq!(Err(ConversionError::UnknownLabel), Belnapian::from_tag(tag));
+        }
+        for tag in ["Both", "unknown", "Unknown(_FT_)"] {
+            assert_eq!(
+                Err(ConversionError::UnknownLabel),
+                TernaryTruth::from_tag(tag)
+            );
+        }
+        for tag in ["____", "N___", "Unknown(_FT_)", "_ft_"] {
+            assert_eq!(Err(ConversionError::UnknownLabel), Unknown::from_tag(tag));
+        }
+        for tag in ["", "Neither", "NFTB ", "Unknown(NFTB)"] {
+            assert_eq!(
+                Err(ConversionError::UnknownLabel),
+                TruthValuesPowerSet::from_tag(tag)
+            );
+        }
+        for tag in [
+            "_FT_",
+            "Unknown",
+            "Unknown(____)",
+            "Unknown(N___)",
+            "Unknown{False,True}",
+        ] {
+            assert_eq!(
+                Err(ConversionError::UnknownLabel),
+                EBelnapian::from_tag(tag)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod hash_tests {
+    use belnapian::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_hash_sets() {
+        let belnapians: HashSet<Belnapian> = Belnapian::ALL.into_iter().collect();
+        assert_eq!(4, belnapians.len());
+
+        let ebelnapians: HashSet<EBelnapian> = EBelnapian::all().into_iter().collect();
+        assert_eq!(15, ebelnapians.len());
+
+        let unknowns: HashSet<Unknown> = EBelnapian::all()
+            .into_iter()
+            .filter_map(|value| Unknown::try_from(value).ok())
+            .collect();
+        assert_eq!(11, unknowns.len());
+
+        let sets: HashSet<TruthValuesPowerSet> = (0b0000..=0b1111u8)
+            .map(|bits| TruthValuesPowerSet::try_from(bits).unwrap())
+            .collect();
+        assert_eq!(16, sets.len());
+
+        // The nested variants hash apart from the known values they resemble
+        let mixed: HashSet<EBelnapian> = [
+            EBelnapian::Known(Belnapian::Both),
+            EBelnapian::Unknown(Unknown::_FT_),
+            EBelnapian::Unknown(Unknown::_FTB),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(3, mixed.len());
+    }
+}
+
+#[cfg(test)]
+mod all_variants_tests {
+    use belnapian::*;
+    use std::collections::HashSet;
+
+    fn assert_distinct<T: std::hash::Hash + Eq>(values: &[T]) {
+        assert_eq!(values.len(), values.iter().collect::<HashSet<_>>().len());
+    }
+
+    #[test]
+    fn test_all_variants() {
+        assert_eq!(4, Belnapian::ALL.len());
+        assert_eq!(3, TernaryTruth::ALL.len());
+        assert_eq!(11, Unknown::ALL.len());
+        assert_eq!(16, TruthValuesPowerSet::ALL.len());
+        assert_eq!(15, EBelnapian::ALL.len());
+
+        assert_distinct(&Belnapian::ALL);
+        assert_distinct(&TernaryTruth::ALL);
+        assert_distinct(&Unknown::ALL);
+        assert_distinct(&TruthValuesPowerSet::ALL);
+        assert_distinct(&EBelnapian::ALL);
+
+        assert_eq!(EBelnapian::ALL, EBelnapian::all());
+        for (bits, set) in TruthValuesPowerSet::ALL.into_iter().enumerate() {
+            assert_eq!(bits as u8, set.to_bits());
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod truth_table_tests {
+    use belnapian::*;
+
+    #[test]
+    fn test_binary_table() {
+        let table = truth_table_binary(Belnapian::and, &Belnapian::ALL);
+        assert_eq!(16, table.len());
+
+        let expected_results = [
+            [
+                Belnapian::Neither,
+                Belnapian::False,
+                Belnapian::Neither,
+                Belnapian::False,
+            ],
+            [
+                Belnapian::False,
+                Belnapian::False,
+                Belnapian::False,
+                Belnapian::False,
+            ],
+            [
+                Belnapian::Neither,
+                Belnapian::False,
+                Belnapian::True,
+                Belnapian::Both,
+            ],
+            [
+                Belnapian::False,
+                Belnapian::False,
+                Belnapian::Both,
+                Belnapian::Both,
+            ],
+        ];
+        for (i, (a, b, result)) in table.into_iter().enumerate() {
+            assert_eq!(Belnapian::ALL[i / 4], a);
+            assert_eq!(Belnapian::ALL[i % 4], b);
+            assert_eq!(expected_results[i / 4][i % 4], result);
+        }
+    }
+
+    #[test]
+    fn test_unary_table() {
+        assert_eq!(
+            vec![
+                (TernaryTruth::False, TernaryTruth::True),
+                (TernaryTruth::True, TernaryTruth::False),
+                (TernaryTruth::Unknown, TernaryTruth::Unknown),
+            ],
+            truth_table_unary(TernaryTruth::not, &TernaryTruth::ALL)
+        );
+    }
+
+    #[test]
+    fn test_write_tables() {
+        let mut out = String::new();
+        write_truth_table_unary(
+            &mut out,
+            &truth_table_unary(Belnapian::not, &Belnapian::ALL),
+        )
+        .unwrap();
+        assert_eq!(
+            "Neither | Neither\nFalse | True\nTrue | False\nBoth | Both\n",
+            out
+        );
+
+        let mut out = String::new();
+        let domain = [TernaryTruth::False, TernaryTruth::Unknown];
+        write_truth_table_binary(&mut out, &truth_table_binary(TernaryTruth::or, &domain)).unwrap();
+        assert_eq!(
+            "False | False | False\nFalse | Unknown | Unknown\n\
+             Unknown | False | Unknown\nUnknown | Unknown | Unknown\n",
+            out
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "ffi")]
+mod ffi_tests {
+    use belnapian::ffi::*;
+    use belnapian::Belnapian;
+
+    type ExternOp = extern "C" fn(u8, u8) -> u8;
+    type BelnapianOp = fn(Belnapian, Belnapian) -> Belnapian;
+
+    #[test]
+    fn test_extern_functions() {
+        let ops: [(ExternOp, BelnapianOp); 8] = [
+            (belnapian_and, Belnapian::and),
+            (belnapian_or, Belnapian::or),
+            (belnapian_xor, Belnapian::xor),
+            (belnapian_implies, Belnapian::implies),
+            (belnapian_nand, Belnapian::nand),
+            (belnapian_nor, Belnapian::nor),
+            (belnapian_superposition, Belnapian::superposition),
+            (belnapian_annihilation, Belnapian::annihilation),
+        ];
+
+        for (extern_op, op) in ops {
+            for a in Belnapian::ALL {
+                for b in Belnapian::ALL {
+                    assert_eq!(op(a, b).as_u8(), extern_op(a.as_u8(), b.as_u8()));
+                }
+                assert_eq!(BELNAPIAN_INVALID, extern_op(a.as_u8(), 4));
+                assert_eq!(BELNAPIAN_INVALID, extern_op(u8::MAX, a.as_u8()));
+            }
+        }
+
+        for a in Belnapian::ALL {
+            assert_eq!(a.not().as_u8(), belnapian_not(a.as_u8()));
+        }
+        assert_eq!(BELNAPIAN_INVALID, belnapian_not(7));
+    }
+}
+
+#[cfg(test)]
+mod lattice_law_tests {
+    use belnapian::Belnapian;
+
+    type BelnapianOp = fn(Belnapian, Belnapian) -> Belnapian;
+
+    const KNOWLEDGE_OPS: [(&str, BelnapianOp); 2] = [
+        ("superposition", Belnapian::superposition),
+        ("annihilation", Belnapian::annihilation),
+    ];
+
+    #[test]
+    fn test_semilattice_laws() {
+        for (name, op) in KNOWLEDGE_OPS {
+            for a in Belnapian::ALL {
+                assert_eq!(a, op(a, a), "{name} idempotence: {a:?}");
+
+                for b in Belnapian::ALL {
+                    assert_eq!(op(a, b), op(b, a), "{name} commutativity: {a:?}, {b:?}");
+
+                    for c in Belnapian::ALL {
+                        assert_eq!(
+                            op(a, op(b, c)),
+                            op(op(a, b), c),
+                            "{name} associativity: {a:?}, {b:?}, {c:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_absorption() {
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                assert_eq!(a, a.superposition(a.annihilation(b)), "{a:?}, {b:?}");
+                assert_eq!(a, a.annihilation(a.superposition(b)), "{a:?}, {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_knowledge_order() {
+        for a in Belnapian::ALL {
+            for b in Belnapian::ALL {
+                let join = a.superposition(b);
+                let meet = a.annihilation(b);
+                assert!(a.knowledge_le(join) && b.knowledge_le(join));
+                assert!(meet.knowledge_le(a) && meet.knowledge_le(b));
+            }
+        }
     }
 }